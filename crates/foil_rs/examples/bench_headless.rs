@@ -104,6 +104,7 @@ Defaults:\n\
             mach: 0.10,
             viscous: true,
             free_transition: true,
+            ..Default::default()
         };
         let (summary, last_len) = bench_polar_sweep(
             &params,
@@ -227,7 +228,7 @@ fn time_polar_sweep(
     let mut last_len = 0usize;
     let start = Instant::now();
     for _ in 0..iters {
-        let rows = if threads <= 1 {
+        let sweep = if threads <= 1 {
             black_box(compute_polar_sweep(
                 params,
                 flow,
@@ -245,8 +246,8 @@ fn time_polar_sweep(
                 Some(threads),
             ))
         };
-        last_len = rows.len();
-        black_box(&rows);
+        last_len = sweep.rows.len();
+        black_box(&sweep);
     }
     (start.elapsed(), last_len)
 }
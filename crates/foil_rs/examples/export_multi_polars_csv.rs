@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
-use foil_rs::solvers::compute_multi_polar_sweeps;
+use foil_rs::solvers::{PolarSweepResult, compute_multi_polar_sweeps};
 use foil_rs::state::{FlowSettings, NacaParams};
 
 fn main() {
@@ -63,6 +63,7 @@ fn main() {
                 mach,
                 viscous,
                 free_transition,
+                ..Default::default()
             });
         }
     }
@@ -86,12 +87,22 @@ fn main() {
         Some(threads.max(1)),
     );
 
+    for (flow, sweep) in &sweeps {
+        for err in &sweep.errors {
+            eprintln!(
+                "warning: approximate row at Re {:.0}, M {:.2}, {:.2} deg: {}",
+                flow.reynolds, flow.mach, err.alpha_deg, err.error
+            );
+        }
+    }
+
     if let Err(err) = write_multi_polar_csv(&out_path, &sweeps) {
         eprintln!("failed to write {}: {err}", out_path.display());
         std::process::exit(1);
     }
 
-    let total_rows: usize = sweeps.iter().map(|(_, r)| r.len()).sum();
+    let total_rows: usize =
+        sweeps.iter().map(|(_, sweep)| sweep.rows.len()).sum();
     println!(
         "saved {} curves / {} rows to {}",
         sweeps.len(),
@@ -139,14 +150,14 @@ fn parse_csv_f32(text: &str) -> Vec<f32> {
 
 fn write_multi_polar_csv(
     path: &Path,
-    sweeps: &[(FlowSettings, Vec<foil_rs::solvers::PolarRow>)],
+    sweeps: &[(FlowSettings, PolarSweepResult)],
 ) -> std::io::Result<()> {
     let file = File::create(path)?;
     let mut out = BufWriter::new(file);
 
     out.write_all(b"curve_id,alpha_deg,cl,cm_c4,cd_profile,mach,reynolds,viscous,free_transition,probable_stall\n")?;
-    for (curve_id, (flow, rows)) in sweeps.iter().enumerate() {
-        for r in rows {
+    for (curve_id, (flow, sweep)) in sweeps.iter().enumerate() {
+        for r in &sweep.rows {
             let cd = r.cd_profile.unwrap_or(f32::NAN);
             writeln!(
                 out,
@@ -56,9 +56,10 @@ fn main() {
         mach,
         viscous,
         free_transition,
+        ..Default::default()
     };
 
-    let rows = if threads <= 1 {
+    let sweep = if threads <= 1 {
         compute_polar_sweep(
             &params,
             &flow,
@@ -76,6 +77,13 @@ fn main() {
             Some(threads),
         )
     };
+    for err in &sweep.errors {
+        eprintln!(
+            "warning: approximate row at {:.2} deg: {}",
+            err.alpha_deg, err.error
+        );
+    }
+    let rows = sweep.rows;
 
     let path = match out_path {
         Some(p) => p,
@@ -24,6 +24,7 @@ fn main() {
         mach: 0.10,
         viscous: true,
         free_transition: true,
+        ..Default::default()
     };
 
     let sol = compute_panel_solution(&params, flow.alpha_deg);
@@ -0,0 +1,257 @@
+//! Weighted interpolation between two sections (XFoil `INTE`).
+//!
+//! Both loops are splined by arc length and split at their leading edge
+//! (the point farthest from the trailing edge). Each node of the first
+//! loop is paired with the point of the second loop at the same
+//! normalized arc length from the LE on the same surface, and the pair is
+//! mixed linearly. Fractions outside `0..1` extrapolate.
+
+use crate::math::Vec2;
+
+use super::shape::AirfoilShape;
+use super::spline::{LoopSpline, close_run, dedup_points, surface_run};
+use super::validate::{GeometryError, validate_loop};
+
+/// Blend fractions closer to zero than this leave the first shape alone.
+const NEUTRAL_FRACTION: f32 = 1e-6;
+
+/// Blend two closed loops in solver ordering.
+///
+/// `fraction` is the weight of `second`: 0 returns the nodes of `first`,
+/// 1 puts them on the contour of `second`. The result keeps the node
+/// count of `first` and is closed like `build_naca_body_geometry_sharp_te`.
+/// Fails if either input or the blended loop is not a valid section, e.g.
+/// when extrapolating far enough for the surfaces to cross.
+pub fn blend_loops(
+    first: &[Vec2],
+    second: &[Vec2],
+    fraction: f32,
+) -> Result<Vec<Vec2>, GeometryError> {
+    validate_loop(first)?;
+    validate_loop(second)?;
+    let out = interpolate(first, second, fraction);
+    validate_loop(&out)?;
+    Ok(out)
+}
+
+/// Surface run of a loop splined by arc length, with its LE parameter.
+struct Parameterized {
+    nodes: Vec<Vec2>,
+    spline: LoopSpline,
+    s_le: f32,
+}
+
+impl Parameterized {
+    fn new(points: &[Vec2]) -> Option<Self> {
+        let (run, _) = surface_run(points);
+        let nodes = dedup_points(run, 1e-7);
+        if nodes.len() < 5 {
+            return None;
+        }
+        let spline = LoopSpline::new(&nodes);
+        let te = 0.5 * (nodes[0] + nodes[nodes.len() - 1]);
+        let s_le = spline.farthest_from(te);
+        let length = spline.length();
+        (s_le > 0.0 && s_le < length).then_some(Self {
+            nodes,
+            spline,
+            s_le,
+        })
+    }
+
+    /// Signed normalized arc length from the LE: −1 at the lower TE,
+    /// 0 at the LE and +1 at the upper TE.
+    fn fraction_at(&self, s: f32) -> f32 {
+        if s <= self.s_le {
+            (s - self.s_le) / self.s_le
+        } else {
+            (s - self.s_le) / (self.spline.length() - self.s_le)
+        }
+    }
+
+    /// Inverse of `fraction_at`.
+    fn point_at(&self, fraction: f32) -> Vec2 {
+        let s = if fraction <= 0.0 {
+            self.s_le * (1.0 + fraction)
+        } else {
+            self.s_le + fraction * (self.spline.length() - self.s_le)
+        };
+        self.spline.point(s)
+    }
+}
+
+/// Unchecked blend; returns `first` unchanged if either loop is too
+/// degenerate to parameterize.
+fn interpolate(
+    first: &[Vec2],
+    second: &[Vec2],
+    fraction: f32,
+) -> Vec<Vec2> {
+    let (Some(a), Some(b)) =
+        (Parameterized::new(first), Parameterized::new(second))
+    else {
+        return first.to_vec();
+    };
+    let mut out: Vec<Vec2> = a
+        .spline
+        .knots()
+        .zip(&a.nodes)
+        .map(|(s, &p)| p.lerp(b.point_at(a.fraction_at(s)), fraction))
+        .collect();
+    close_run(&mut out);
+    out
+}
+
+/// Mix of two shapes, `fraction` of the way from `first` to `second`.
+///
+/// At a zero fraction the first shape passes through untouched,
+/// including its drawing loop and analytic surfaces. Otherwise the panel
+/// loops are blended with `blend_loops` once, in `new`; see
+/// `try_panel_points` for how a failed blend is handled.
+#[derive(Clone, Debug)]
+pub struct Blended<A, B> {
+    first: A,
+    second: B,
+    fraction: f32,
+    blended: Result<Vec<Vec2>, GeometryError>,
+}
+
+impl<A: AirfoilShape, B: AirfoilShape> Blended<A, B> {
+    pub fn new(first: A, second: B, fraction: f32) -> Self {
+        let first_points = first.panel_points();
+        let blended = if fraction.abs() < NEUTRAL_FRACTION {
+            Ok(first_points)
+        } else {
+            blend_loops(&first_points, &second.panel_points(), fraction)
+        };
+        Self {
+            first,
+            second,
+            fraction,
+            blended,
+        }
+    }
+
+    /// The blended panel loop, or why it is not a valid section.
+    ///
+    /// `panel_points` and `name` fall back to the first shape on error,
+    /// so callers that show the blend should check this first.
+    pub fn try_panel_points(&self) -> Result<&[Vec2], &GeometryError> {
+        self.blended.as_deref()
+    }
+
+    fn is_neutral(&self) -> bool {
+        self.fraction.abs() < NEUTRAL_FRACTION
+    }
+}
+
+impl<A: AirfoilShape, B: AirfoilShape> AirfoilShape for Blended<A, B> {
+    fn name(&self) -> String {
+        if self.is_neutral() || self.blended.is_err() {
+            self.first.name()
+        } else {
+            format!(
+                "{} / {} ({:.0}%)",
+                self.first.name(),
+                self.second.name(),
+                100.0 * self.fraction
+            )
+        }
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        match &self.blended {
+            Ok(points) => points.clone(),
+            Err(_) => self.first.panel_points(),
+        }
+    }
+
+    fn body_points(&self) -> Vec<Vec2> {
+        if self.is_neutral() {
+            self.first.body_points()
+        } else {
+            self.panel_points()
+        }
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        if self.is_neutral() {
+            self.first.analytic_surfaces(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::{GeometryProperties, naca_loop};
+    use crate::state::NacaParams;
+
+    fn props(points: &[Vec2]) -> GeometryProperties {
+        GeometryProperties::from_loop(points).unwrap()
+    }
+
+    #[test]
+    fn end_fractions_reproduce_the_parents() {
+        let (a, b) = (naca_loop("0012"), naca_loop("4415"));
+        let start = blend_loops(&a, &b, 0.0).unwrap();
+        assert_eq!(start.len(), a.len());
+        for (p, q) in start.iter().zip(&a) {
+            assert!(p.distance(*q) < 1e-6, "{p} vs {q}");
+        }
+
+        let end = props(&blend_loops(&a, &b, 1.0).unwrap());
+        let target = props(&b);
+        assert!(
+            (end.max_thickness - target.max_thickness).abs() < 1e-3
+        );
+        assert!((end.max_camber - target.max_camber).abs() < 1e-3);
+    }
+
+    #[test]
+    fn blends_and_extrapolates_thickness_and_camber() {
+        let (a, b) = (naca_loop("0012"), naca_loop("4418"));
+        for fraction in [0.5, 1.5, -0.5] {
+            let out = blend_loops(&a, &b, fraction)
+                .unwrap_or_else(|err| panic!("{fraction}: {err}"));
+            let p = props(&out);
+            let thickness = 0.12 + fraction * 0.06;
+            let camber = fraction * 0.04;
+            assert!(
+                (p.max_thickness - thickness).abs() < 3e-3,
+                "{fraction}: t {}",
+                p.max_thickness
+            );
+            assert!(
+                (p.max_camber - camber).abs() < 2e-3,
+                "{fraction}: camber {}",
+                p.max_camber
+            );
+        }
+    }
+
+    #[test]
+    fn crossed_extrapolation_is_rejected() {
+        let (a, b) = (naca_loop("0012"), naca_loop("0006"));
+        assert!(blend_loops(&a, &b, 2.5).is_err());
+    }
+
+    #[test]
+    fn blended_shape_validates_its_loop() {
+        let thin = NacaParams::from_naca4("0006").unwrap();
+        let base = NacaParams::from_naca4("0012").unwrap();
+        let ok = Blended::new(base.clone(), thin.clone(), 0.5);
+        assert_eq!(
+            ok.try_panel_points().unwrap(),
+            blend_loops(&naca_loop("0012"), &naca_loop("0006"), 0.5)
+                .unwrap()
+        );
+
+        let crossed = Blended::new(base, thin, 2.5);
+        assert!(crossed.try_panel_points().is_err());
+        assert_eq!(crossed.panel_points(), naca_loop("0012"));
+        assert_eq!(crossed.name(), "NACA 0012");
+    }
+}
@@ -0,0 +1,266 @@
+//! Kulfan Class-Shape Transformation (CST) airfoils.
+//!
+//! Each surface is `y(x) = C(x) · S(x) ± x · Δte / 2` with the class
+//! function `C(x) = x^N1 · (1 - x)^N2` (round nose, sharp aft end for the
+//! default `N1 = 0.5`, `N2 = 1`) and a shape function `S(x)` that is a
+//! Bernstein polynomial whose weights are the design variables. Lower
+//! weights describe the lower surface directly, so they are negative for a
+//! conventional section.
+
+use crate::math::{Vec2, least_squares};
+
+use super::naca::{effective_num_points, sharp_te_loop};
+use super::shape::AirfoilShape;
+use super::surfaces::Surfaces;
+
+/// A CST airfoil on the unit chord.
+#[derive(Clone, Debug)]
+pub struct CstAirfoil {
+    /// Upper-surface Bernstein weights; the polynomial order is
+    /// `upper.len() - 1`.
+    pub upper: Vec<f32>,
+    /// Lower-surface Bernstein weights (independent order).
+    pub lower: Vec<f32>,
+    /// Total trailing-edge thickness, split evenly between the surfaces.
+    pub te_thickness: f32,
+    /// Class-function leading-edge exponent, `x^n1` (0.5 for a round
+    /// nose).
+    pub n1: f32,
+    /// Class-function trailing-edge exponent, `(1 - x)^n2` (1.0 for a
+    /// sharp TE).
+    pub n2: f32,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+}
+
+impl Default for CstAirfoil {
+    fn default() -> Self {
+        // Symmetric, roughly 13 % thick.
+        Self::new(vec![0.17; 5], vec![-0.17; 5], 0.0)
+    }
+}
+
+impl CstAirfoil {
+    pub fn new(
+        upper: Vec<f32>,
+        lower: Vec<f32>,
+        te_thickness: f32,
+    ) -> Self {
+        Self {
+            upper,
+            lower,
+            te_thickness,
+            n1: 0.5,
+            n2: 1.0,
+            num_points: 160,
+        }
+    }
+
+    /// `(upper, lower)` surface heights at `x`.
+    pub fn surfaces(&self, x: f32) -> (f32, f32) {
+        let x = x.clamp(0.0, 1.0) as f64;
+        let c = self.class(x);
+        let te = 0.5 * self.te_thickness as f64 * x;
+        let yu = c * bernstein_sum(&self.upper, x) + te;
+        let yl = c * bernstein_sum(&self.lower, x) - te;
+        (yu as f32, yl as f32)
+    }
+
+    /// `(upper, lower)` surface slopes `dy/dx` at `x`.
+    ///
+    /// The round nose has an infinite slope at `x = 0`, so `x` is kept a
+    /// hair inside the chord.
+    pub fn slopes(&self, x: f32) -> (f32, f32) {
+        let x = (x as f64).clamp(1e-9, 1.0 - 1e-9);
+        let c = self.class(x);
+        let dc = c * (self.n1 as f64 / x - self.n2 as f64 / (1.0 - x));
+        let te = 0.5 * self.te_thickness as f64;
+        let slope = |w: &[f32]| {
+            dc * bernstein_sum(w, x) + c * bernstein_derivative(w, x)
+        };
+        (
+            (slope(&self.upper) + te) as f32,
+            (slope(&self.lower) - te) as f32,
+        )
+    }
+
+    /// Fit CST weights of the given orders to any coordinate loop on the
+    /// unit chord (e.g. `build_naca_body_geometry_sharp_te`).
+    ///
+    /// The TE thickness is taken from the loop's end points and each
+    /// surface is fitted by linear least squares. Returns `None` if the
+    /// loop is too short or the fit is singular (more weights than points).
+    pub fn fit(
+        points: &[Vec2],
+        upper_order: usize,
+        lower_order: usize,
+    ) -> Option<Self> {
+        let surfaces = Surfaces::from_loop(points);
+        let te_upper = surfaces.upper.last()?;
+        let te_lower = surfaces.lower.last()?;
+        let te_thickness = (te_upper.y - te_lower.y).max(0.0);
+
+        let mut cst = Self::new(Vec::new(), Vec::new(), te_thickness);
+        cst.upper =
+            cst.fit_surface(&surfaces.upper, upper_order, 1.0)?;
+        cst.lower =
+            cst.fit_surface(&surfaces.lower, lower_order, -1.0)?;
+        Some(cst)
+    }
+
+    fn fit_surface(
+        &self,
+        pts: &[Vec2],
+        order: usize,
+        side: f64,
+    ) -> Option<Vec<f32>> {
+        let mut rows = Vec::with_capacity(pts.len());
+        let mut rhs = Vec::with_capacity(pts.len());
+        for p in pts {
+            let x = (p.x as f64).clamp(0.0, 1.0);
+            let c = self.class(x);
+            rows.push(
+                (0..=order)
+                    .map(|i| c * bernstein(order, i, x))
+                    .collect(),
+            );
+            rhs.push(
+                p.y as f64 - side * 0.5 * self.te_thickness as f64 * x,
+            );
+        }
+        let weights = least_squares(&rows, &rhs)?;
+        Some(weights.into_iter().map(|w| w as f32).collect())
+    }
+
+    fn class(&self, x: f64) -> f64 {
+        x.powf(self.n1 as f64) * (1.0 - x).powf(self.n2 as f64)
+    }
+}
+
+/// Binomial coefficient `n choose k`.
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Bernstein basis polynomial `b_{i,n}(x)`.
+fn bernstein(n: usize, i: usize, x: f64) -> f64 {
+    binomial(n, i) * x.powi(i as i32) * (1.0 - x).powi((n - i) as i32)
+}
+
+fn bernstein_sum(weights: &[f32], x: f64) -> f64 {
+    let Some(n) = weights.len().checked_sub(1) else {
+        return 0.0;
+    };
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| w as f64 * bernstein(n, i, x))
+        .sum()
+}
+
+/// Derivative of the Bernstein sum (degree-elevation form).
+fn bernstein_derivative(weights: &[f32], x: f64) -> f64 {
+    let Some(n) = weights.len().checked_sub(1) else {
+        return 0.0;
+    };
+    if n == 0 {
+        return 0.0;
+    }
+    weights
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            n as f64 * (w[1] - w[0]) as f64 * bernstein(n - 1, i, x)
+        })
+        .sum()
+}
+
+/// Build a CST airfoil as a closed loop (straight closing segment across
+/// a finite trailing edge).
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`.
+pub fn build_cst_geometry(cst: &CstAirfoil) -> Vec<Vec2> {
+    sharp_te_loop(cst.num_points, |x| {
+        let (yu, yl) = cst.surfaces(x);
+        (Vec2::new(x, yu), Vec2::new(x, yl))
+    })
+}
+
+impl AirfoilShape for CstAirfoil {
+    fn name(&self) -> String {
+        format!("CST {}/{}", self.upper.len(), self.lower.len())
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_cst_geometry(&local)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        let (yu, yl) = self.surfaces(x);
+        Some((Vec2::new(x, yu), Vec2::new(x, yl)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::build_naca_body_geometry_sharp_te;
+    use crate::state::NacaParams;
+
+    #[test]
+    fn fit_reproduces_naca_loop() {
+        let params = NacaParams::default();
+        let pts = build_naca_body_geometry_sharp_te(&params);
+        let cst = CstAirfoil::fit(&pts, 8, 8).expect("fit");
+
+        // The cambered NACA nose sits slightly ahead of and above the
+        // origin, which the CST class function cannot follow; compare aft
+        // of the first percent of chord.
+        let surfaces = Surfaces::from_loop(&pts);
+        let max_err = |side: &[Vec2], upper: bool| {
+            side.iter()
+                .filter(|p| p.x > 0.01)
+                .map(|p| {
+                    let (yu, yl) = cst.surfaces(p.x);
+                    (if upper { yu } else { yl } - p.y).abs()
+                })
+                .fold(0.0, f32::max)
+        };
+        assert!(max_err(&surfaces.upper, true) < 5e-4);
+        assert!(max_err(&surfaces.lower, false) < 5e-4);
+        assert!(cst.upper.iter().all(|w| w.is_finite()));
+    }
+
+    #[test]
+    fn slopes_match_finite_differences() {
+        let cst = CstAirfoil::new(
+            vec![0.2, 0.15, 0.18, 0.1],
+            vec![-0.12, -0.05, 0.02],
+            0.004,
+        );
+        for x in [0.05, 0.3, 0.7, 0.95] {
+            let h = 1e-3;
+            let (u0, l0) = cst.surfaces(x - h);
+            let (u1, l1) = cst.surfaces(x + h);
+            let (su, sl) = cst.slopes(x);
+            assert!(((u1 - u0) / (2.0 * h) - su).abs() < 2e-3, "x={x}");
+            assert!(((l1 - l0) / (2.0 * h) - sl).abs() < 2e-3, "x={x}");
+        }
+    }
+
+    #[test]
+    fn te_thickness_is_split_between_surfaces() {
+        let cst = CstAirfoil::new(vec![0.2; 4], vec![-0.2; 4], 0.01);
+        let (yu, yl) = cst.surfaces(1.0);
+        assert!((yu - 0.005).abs() < 1e-6 && (yl + 0.005).abs() < 1e-6);
+
+        let pts = build_cst_geometry(&cst);
+        let refit = CstAirfoil::fit(&pts, 3, 3).expect("refit");
+        assert!((refit.te_thickness - 0.01).abs() < 1e-5);
+        for (a, b) in refit.upper.iter().zip(&cst.upper) {
+            assert!((a - b).abs() < 1e-3, "{a} vs {b}");
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//! Reading and writing Selig / Lednicer `.dat` coordinate files.
+//!
+//! Both formats start with an optional name line followed by `x y` pairs.
+//! - Selig: a single loop from the trailing edge over the upper surface to
+//!   the leading edge and back along the lower surface.
+//! - Lednicer: a `NU NL` point-count header, then the upper surface
+//!   (LE → TE) followed by the lower surface (LE → TE).
+
+use std::fmt;
+use std::path::Path;
+
+use crate::math::Vec2;
+
+use super::repanel::{PanelingParams, repanel};
+use super::spline::surface_run;
+
+/// Coordinate file layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatFormat {
+    Selig,
+    Lednicer,
+}
+
+/// A parsed `.dat` airfoil.
+#[derive(Clone, Debug)]
+pub struct DatAirfoil {
+    /// Name from the header line (empty if the file has none).
+    pub name: String,
+    /// Layout the file was written in.
+    pub format: DatFormat,
+    /// Closed loop in `AirfoilShape::panel_points` ordering.
+    pub points: Vec<Vec2>,
+}
+
+/// Errors reported while reading a `.dat` file.
+///
+/// Line numbers are 1-based and refer to the original text.
+#[derive(Debug)]
+pub enum DatError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// No coordinate lines were found.
+    Empty,
+    /// A line did not contain exactly two values.
+    WrongColumnCount { line: usize, found: usize },
+    /// A value could not be parsed as a number.
+    InvalidNumber { line: usize, text: String },
+    /// A value parsed but is NaN or infinite.
+    NonFinite { line: usize },
+    /// The Lednicer header on `header_line` announced a different number
+    /// of points than the file contains. `line` is the last data line
+    /// when the data runs short, or the first extra line when it runs
+    /// long.
+    CountMismatch {
+        header_line: usize,
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Too few points to describe a closed section.
+    TooFewPoints { found: usize },
+}
+
+impl fmt::Display for DatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => {
+                write!(f, "failed to read .dat file: {err}")
+            }
+            Self::Empty => write!(f, "no coordinates found"),
+            Self::WrongColumnCount { line, found } => write!(
+                f,
+                "line {line}: expected 2 values, found {found}"
+            ),
+            Self::InvalidNumber { line, text } => {
+                write!(f, "line {line}: invalid number '{text}'")
+            }
+            Self::NonFinite { line } => {
+                write!(f, "line {line}: non-finite coordinate")
+            }
+            Self::CountMismatch {
+                header_line,
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: Lednicer header on line {header_line} \
+                 announces {expected} points, found {found}"
+            ),
+            Self::TooFewPoints { found } => write!(
+                f,
+                "expected at least {MIN_POINTS} points, found {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DatError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+const MIN_POINTS: usize = 5;
+
+/// Read and parse a `.dat` file from disk.
+pub fn load_dat(
+    path: impl AsRef<Path>,
+) -> Result<DatAirfoil, DatError> {
+    let text = std::fs::read_to_string(path)?;
+    parse_dat(&text)
+}
+
+/// Parse `.dat` text, detecting Selig vs Lednicer layout automatically.
+pub fn parse_dat(text: &str) -> Result<DatAirfoil, DatError> {
+    let mut name = String::new();
+    let mut rows: Vec<(usize, Vec2)> = Vec::new();
+
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // The first non-empty line is a name unless it parses as data.
+        if rows.is_empty() && name.is_empty() {
+            match parse_pair(line, line_no) {
+                Ok(pair) => rows.push((line_no, pair)),
+                Err(_) => name = line.to_string(),
+            }
+            continue;
+        }
+
+        rows.push((line_no, parse_pair(line, line_no)?));
+    }
+
+    if rows.is_empty() {
+        return Err(DatError::Empty);
+    }
+
+    let (format, points) = match lednicer_counts(rows[0].1) {
+        Some((n_upper, n_lower)) => {
+            let data = &rows[1..];
+            let expected = n_upper + n_lower;
+            if data.len() != expected {
+                let header_line = rows[0].0;
+                let line = data
+                    .get(expected)
+                    .or(data.last())
+                    .map_or(header_line, |r| r.0);
+                return Err(DatError::CountMismatch {
+                    header_line,
+                    line,
+                    expected,
+                    found: data.len(),
+                });
+            }
+            let upper: Vec<Vec2> =
+                data[..n_upper].iter().map(|r| r.1).collect();
+            let lower: Vec<Vec2> =
+                data[n_upper..].iter().map(|r| r.1).collect();
+            (DatFormat::Lednicer, loop_from_lednicer(&upper, &lower))
+        }
+        None => {
+            let pts: Vec<Vec2> = rows.iter().map(|r| r.1).collect();
+            (DatFormat::Selig, loop_from_selig(pts))
+        }
+    };
+
+    // Count distinct points (the closing point repeats the first one).
+    let distinct = points.len().saturating_sub(1);
+    if distinct < MIN_POINTS {
+        return Err(DatError::TooFewPoints { found: distinct });
+    }
+
+    Ok(DatAirfoil {
+        name,
+        format,
+        points,
+    })
+}
+
+fn parse_pair(line: &str, line_no: usize) -> Result<Vec2, DatError> {
+    let tokens: Vec<&str> = line
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.len() != 2 {
+        return Err(DatError::WrongColumnCount {
+            line: line_no,
+            found: tokens.len(),
+        });
+    }
+
+    let mut values = [0.0f32; 2];
+    for (value, token) in values.iter_mut().zip(&tokens) {
+        *value = token.parse::<f32>().map_err(|_| {
+            DatError::InvalidNumber {
+                line: line_no,
+                text: token.to_string(),
+            }
+        })?;
+        if !value.is_finite() {
+            return Err(DatError::NonFinite { line: line_no });
+        }
+    }
+    Ok(Vec2::new(values[0], values[1]))
+}
+
+/// A Lednicer file's first data row holds integral point counts (e.g.
+/// `61.  61.`), which can never be a valid unit-chord coordinate.
+fn lednicer_counts(first: Vec2) -> Option<(usize, usize)> {
+    let is_count = |v: f32| v >= 2.0 && v.fract() == 0.0;
+    if is_count(first.x) && is_count(first.y) {
+        Some((first.x as usize, first.y as usize))
+    } else {
+        None
+    }
+}
+
+fn loop_from_selig(mut pts: Vec<Vec2>) -> Vec<Vec2> {
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    // Selig files run upper-first; a few tools write lower-first. Compare
+    // the mean height of the two halves around the leading edge.
+    let le = leading_edge_index(&pts);
+    let mean_y = |s: &[Vec2]| {
+        s.iter().map(|p| p.y).sum::<f32>() / s.len().max(1) as f32
+    };
+    if mean_y(&pts[..=le]) > mean_y(&pts[le..]) {
+        pts.reverse();
+    }
+
+    close_loop(&mut pts);
+    pts
+}
+
+fn loop_from_lednicer(upper: &[Vec2], lower: &[Vec2]) -> Vec<Vec2> {
+    let mut pts: Vec<Vec2> =
+        Vec::with_capacity(upper.len() + lower.len());
+    pts.extend(lower.iter().rev());
+
+    // Both surfaces normally share the leading-edge point.
+    let skip = match (lower.first(), upper.first()) {
+        (Some(a), Some(b)) if a.distance(*b) < 1e-7 => 1,
+        _ => 0,
+    };
+    pts.extend(upper.iter().skip(skip));
+
+    close_loop(&mut pts);
+    pts
+}
+
+fn leading_edge_index(pts: &[Vec2]) -> usize {
+    pts.iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+fn close_loop(pts: &mut Vec<Vec2>) {
+    if let (Some(&first), Some(&last)) = (pts.first(), pts.last())
+        && first.distance(last) > 1e-7
+    {
+        pts.push(first);
+    }
+}
+
+/// Options for `write_dat`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatWriteOptions {
+    pub format: DatFormat,
+    /// Name line written above the coordinates; `None` omits it.
+    pub name: Option<String>,
+    /// Repanel to this many nodes (`PanelingParams::num_nodes`) before
+    /// writing; `None` writes the loop's own points.
+    pub num_points: Option<usize>,
+    /// Digits after the decimal point.
+    pub precision: usize,
+}
+
+impl Default for DatWriteOptions {
+    fn default() -> Self {
+        Self {
+            format: DatFormat::Selig,
+            name: None,
+            num_points: None,
+            precision: 6,
+        }
+    }
+}
+
+/// Format a loop in solver ordering (see `DatAirfoil::points`) as `.dat`
+/// text that `parse_dat` reads back into the same loop.
+pub fn write_dat(points: &[Vec2], options: &DatWriteOptions) -> String {
+    let resampled;
+    let mut points = points;
+    if let Some(num_nodes) = options.num_points {
+        resampled = repanel(
+            points,
+            &PanelingParams {
+                num_nodes,
+                ..Default::default()
+            },
+        );
+        points = &resampled;
+    }
+    let (upper, lower) = split_loop(points);
+
+    let prec = options.precision;
+    let width = prec + 4;
+    let row = |p: &Vec2| {
+        format!("{:>width$.prec$} {:>width$.prec$}\n", p.x, p.y)
+    };
+
+    let mut out = String::new();
+    if let Some(name) = &options.name {
+        out.push_str(name.trim());
+        out.push('\n');
+    }
+    match options.format {
+        DatFormat::Selig => {
+            upper.iter().rev().for_each(|p| out.push_str(&row(p)));
+            lower.iter().skip(1).for_each(|p| out.push_str(&row(p)));
+        }
+        DatFormat::Lednicer => {
+            out.push_str(&format!(
+                "{}. {}.\n\n",
+                upper.len(),
+                lower.len()
+            ));
+            upper.iter().for_each(|p| out.push_str(&row(p)));
+            out.push('\n');
+            lower.iter().for_each(|p| out.push_str(&row(p)));
+        }
+    }
+    out
+}
+
+/// Write a loop to disk with `write_dat`.
+pub fn save_dat(
+    path: impl AsRef<Path>,
+    points: &[Vec2],
+    options: &DatWriteOptions,
+) -> std::io::Result<()> {
+    std::fs::write(path, write_dat(points, options))
+}
+
+/// Split a solver-ordered loop into `(upper, lower)`, both LE → TE and
+/// sharing the leading-edge point. A blunt TE ends the two surfaces on
+/// separate points, a sharp one on the same point.
+fn split_loop(points: &[Vec2]) -> (Vec<Vec2>, Vec<Vec2>) {
+    let (pts, _) = surface_run(points);
+    if pts.len() < 3 {
+        return (pts.to_vec(), Vec::new());
+    }
+    let le = leading_edge_index(pts);
+    let lower = pts[..=le].iter().rev().copied().collect();
+    let upper = pts[le..].to_vec();
+    (upper, lower)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SELIG: &str = "\
+NACA 0012-ish
+1.0   0.0
+0.5   0.06
+0.1   0.04
+0.0   0.0
+0.1  -0.04
+0.5  -0.06
+1.0   0.0
+";
+
+    const LEDNICER: &str = "\
+Lednicer sample
+3. 3.
+
+0.0  0.0
+0.5  0.06
+1.0  0.001
+
+0.0  0.0
+0.5 -0.04
+1.0 -0.001
+";
+
+    #[test]
+    fn selig_is_reordered_lower_first_and_closed() {
+        let dat = parse_dat(SELIG).expect("parse Selig");
+        assert_eq!(dat.format, DatFormat::Selig);
+        assert_eq!(dat.name, "NACA 0012-ish");
+        assert_eq!(dat.points.len(), 7);
+        assert!(dat.points[1].y < 0.0, "expected lower surface first");
+        assert!(dat.points[5].y > 0.0, "expected upper surface last");
+        assert_eq!(dat.points.first(), dat.points.last());
+    }
+
+    #[test]
+    fn lednicer_merges_surfaces_and_closes_blunt_te() {
+        let dat = parse_dat(LEDNICER).expect("parse Lednicer");
+        assert_eq!(dat.format, DatFormat::Lednicer);
+        let expected = [
+            Vec2::new(1.0, -0.001),
+            Vec2::new(0.5, -0.04),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.5, 0.06),
+            Vec2::new(1.0, 0.001),
+            Vec2::new(1.0, -0.001),
+        ];
+        assert_eq!(dat.points, expected);
+    }
+
+    #[test]
+    fn errors_report_line_numbers() {
+        let text = "name\n1.0 0.0\n0.5 abc\n";
+        match parse_dat(text) {
+            Err(DatError::InvalidNumber { line, text }) => {
+                assert_eq!(line, 3);
+                assert_eq!(text, "abc");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let text = "name\n1.0 0.0 2.0\n";
+        assert!(matches!(
+            parse_dat(text),
+            Err(DatError::WrongColumnCount { line: 2, found: 3 })
+        ));
+    }
+
+    #[test]
+    fn lednicer_count_mismatch_is_rejected() {
+        // Runs short: reported on the last data line.
+        let text = "x\n3. 3.\n0 0\n0.5 0.05\n1 0\n0 0\n1 0\n";
+        let err = parse_dat(text).unwrap_err();
+        assert!(matches!(
+            err,
+            DatError::CountMismatch {
+                header_line: 2,
+                line: 7,
+                expected: 6,
+                found: 5
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 7: Lednicer header on line 2 announces 6 points, found 5"
+        );
+
+        // Runs long: reported on the first extra line.
+        let text = "x\n\n2. 2.\n0 0\n1 0\n0 0\n1 0\n1 0\n";
+        assert!(matches!(
+            parse_dat(text),
+            Err(DatError::CountMismatch {
+                header_line: 3,
+                line: 8,
+                expected: 4,
+                found: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn written_files_parse_back_to_the_same_loop() {
+        // Open 4-digit TE, and a sharp TE that ends on the first point.
+        let blunt = crate::airfoil::naca_loop("2412");
+        let sharp = parse_dat(SELIG).unwrap().points;
+        for points in [blunt, sharp] {
+            for format in [DatFormat::Selig, DatFormat::Lednicer] {
+                let options = DatWriteOptions {
+                    format,
+                    name: Some("test foil".into()),
+                    ..Default::default()
+                };
+                let dat = parse_dat(&write_dat(&points, &options))
+                    .expect("parse written file");
+                assert_eq!(dat.format, format);
+                assert_eq!(dat.name, "test foil");
+                assert_eq!(dat.points.len(), points.len());
+                for (a, b) in dat.points.iter().zip(&points) {
+                    assert!(a.distance(*b) < 1e-6, "{a} vs {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn writer_honours_point_count_and_precision() {
+        let points = parse_dat(SELIG).unwrap().points;
+        let text = write_dat(
+            &points,
+            &DatWriteOptions {
+                num_points: Some(41),
+                precision: 4,
+                ..Default::default()
+            },
+        );
+        let lines: Vec<&str> = text.lines().collect();
+        // Sharp TE: 41 nodes with the TE written at both ends.
+        assert_eq!(lines.len(), 41);
+        assert_eq!(lines[0], "  1.0000   0.0000");
+        assert_eq!(lines.first(), lines.last());
+    }
+}
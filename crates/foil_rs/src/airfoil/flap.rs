@@ -0,0 +1,334 @@
+//! Plain flap deflection (XFoil `GDES` → `FLAP`).
+//!
+//! Everything aft of the hinge line `x = hinge_x` is rotated about the
+//! hinge point. On the surface that opens up (the upper surface for a
+//! trailing-edge-down deflection) the gap is filled with an arc about the
+//! hinge; on the surface that folds in, the fixed and rotated parts are
+//! trimmed back to where they cross.
+
+use crate::math::Vec2;
+
+use super::shape::AirfoilShape;
+use super::spline::{close_run, surface_run};
+use super::surfaces::Surfaces;
+
+/// Largest angle spanned by one panel of the gap-filling arc.
+const ARC_STEP_DEG: f32 = 5.0;
+
+/// Vertical position of the hinge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HingeY {
+    /// Absolute height (y/c for a unit-chord loop).
+    Absolute(f32),
+    /// Fraction of the local thickness above the lower surface
+    /// (0 = lower surface, 1 = upper surface).
+    ThicknessFraction(f32),
+}
+
+/// Flap hinge and deflection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlapParams {
+    /// Chordwise hinge position (x/c for a unit-chord loop).
+    pub hinge_x: f32,
+    pub hinge_y: HingeY,
+    /// Deflection in degrees, positive trailing edge down.
+    pub deflection_deg: f32,
+}
+
+impl Default for FlapParams {
+    fn default() -> Self {
+        Self {
+            hinge_x: 0.75,
+            hinge_y: HingeY::ThicknessFraction(0.5),
+            deflection_deg: 0.0,
+        }
+    }
+}
+
+impl FlapParams {
+    /// Whether the flap leaves the section unchanged.
+    pub fn is_neutral(&self) -> bool {
+        self.deflection_deg.abs() < 1e-4
+    }
+
+    /// Hinge point for a loop in solver ordering, or `None` if the hinge
+    /// line misses the section.
+    pub fn hinge_point(&self, points: &[Vec2]) -> Option<Vec2> {
+        let (upper, lower) = split_surfaces(points)?;
+        hinge_on(&upper, &lower, self)
+    }
+}
+
+/// Deflect the aft part of a closed loop in solver ordering.
+///
+/// The result keeps the ordering and closure of the input: TE lower → LE
+/// → TE upper, ending on the first point. Returns the input unchanged for
+/// a neutral flap or a hinge outside the section.
+pub fn deflect_flap(points: &[Vec2], params: &FlapParams) -> Vec<Vec2> {
+    if params.is_neutral() {
+        return points.to_vec();
+    }
+    let Some((upper, lower)) = split_surfaces(points) else {
+        return points.to_vec();
+    };
+    let Some(hinge) = hinge_on(&upper, &lower, params) else {
+        return points.to_vec();
+    };
+    // Positive deflection turns the flap clockwise (TE down).
+    let angle = -params.deflection_deg.to_radians();
+    let upper = deflect_surface(&upper, hinge, params.hinge_x, angle);
+    let lower = deflect_surface(&lower, hinge, params.hinge_x, angle);
+
+    let mut out: Vec<Vec2> =
+        Vec::with_capacity(upper.len() + lower.len() + 1);
+    out.extend(lower.iter().rev());
+    out.extend(upper.iter().skip(1));
+    close_run(&mut out);
+    out
+}
+
+/// `(upper, lower)` surfaces, both LE → TE and sharing the LE point.
+fn split_surfaces(points: &[Vec2]) -> Option<(Vec<Vec2>, Vec<Vec2>)> {
+    let (run, _) = surface_run(points);
+    if run.len() < 5 {
+        return None;
+    }
+    let le = run
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+        .map(|(i, _)| i)?;
+    let lower = run[..=le].iter().rev().copied().collect();
+    Some((run[le..].to_vec(), lower))
+}
+
+fn hinge_on(
+    upper: &[Vec2],
+    lower: &[Vec2],
+    params: &FlapParams,
+) -> Option<Vec2> {
+    let x = params.hinge_x;
+    let x_le = upper[0].x;
+    let x_te = upper[upper.len() - 1].x.min(lower[lower.len() - 1].x);
+    if !(x > x_le && x < x_te) {
+        return None;
+    }
+    let y = match params.hinge_y {
+        HingeY::Absolute(y) => y,
+        HingeY::ThicknessFraction(f) => {
+            let surfaces = Surfaces {
+                upper: upper.to_vec(),
+                lower: lower.to_vec(),
+            };
+            let (yu, yl) = (surfaces.upper_y(x), surfaces.lower_y(x));
+            yl + f * (yu - yl)
+        }
+    };
+    Some(Vec2::new(x, y))
+}
+
+/// Deflect one surface (LE → TE) about `hinge` by `angle` (radians,
+/// counter-clockwise).
+fn deflect_surface(
+    surface: &[Vec2],
+    hinge: Vec2,
+    hinge_x: f32,
+    angle: f32,
+) -> Vec<Vec2> {
+    let split = surface.partition_point(|p| p.x < hinge_x);
+    if split == 0 || split == surface.len() {
+        return surface.to_vec();
+    }
+    // Surface point on the hinge line: it ends the fixed part and, once
+    // rotated, starts the flap.
+    let (a, b) = (surface[split - 1], surface[split]);
+    let t = ((hinge_x - a.x) / (b.x - a.x)).clamp(0.0, 1.0);
+    let break_point = a.lerp(b, t);
+
+    let mut fixed = surface[..split].to_vec();
+    if fixed[fixed.len() - 1].distance(break_point) > 1e-7 {
+        fixed.push(break_point);
+    }
+    let rot = Vec2::from_angle(angle);
+    let mut flap: Vec<Vec2> =
+        Vec::with_capacity(surface.len() - split + 1);
+    flap.push(break_point);
+    flap.extend(&surface[split..]);
+    if flap[1].distance(break_point) <= 1e-7 {
+        flap.remove(1);
+    }
+    for p in &mut flap {
+        *p = hinge + rot.rotate(*p - hinge);
+    }
+
+    let mut out = fixed.clone();
+    if let Some((i, j, x)) = first_crossing(&fixed, &flap) {
+        // Folding side: keep the fixed part up to the crossing and
+        // continue on the flap from there.
+        out.truncate(i + 1);
+        out.push(x);
+        out.extend(&flap[j + 1..]);
+    } else {
+        // Opening side: arc about the hinge from the fixed end to the
+        // start of the rotated flap.
+        let arm = break_point - hinge;
+        let steps =
+            (angle.abs().to_degrees() / ARC_STEP_DEG).ceil() as usize;
+        for k in 1..steps {
+            let r = Vec2::from_angle(angle * k as f32 / steps as f32);
+            out.push(hinge + r.rotate(arm));
+        }
+        out.extend(&flap);
+    }
+    out.dedup_by(|a, b| a.distance(*b) <= 1e-7);
+    out
+}
+
+/// Crossing between the fixed part and the rotated flap nearest the
+/// hinge: `(fixed segment, flap segment, point)`.
+fn first_crossing(
+    fixed: &[Vec2],
+    flap: &[Vec2],
+) -> Option<(usize, usize, Vec2)> {
+    for i in (0..fixed.len() - 1).rev() {
+        for j in 0..flap.len() - 1 {
+            if let Some(x) = segment_intersection(
+                fixed[i],
+                fixed[i + 1],
+                flap[j],
+                flap[j + 1],
+            ) {
+                return Some((i, j, x));
+            }
+        }
+    }
+    None
+}
+
+fn segment_intersection(
+    a0: Vec2,
+    a1: Vec2,
+    b0: Vec2,
+    b1: Vec2,
+) -> Option<Vec2> {
+    let (da, db) = (a1 - a0, b1 - b0);
+    let denom = da.perp_dot(db);
+    if denom.abs() < f32::EPSILON * da.length() * db.length() {
+        return None;
+    }
+    let s = (b0 - a0).perp_dot(db) / denom;
+    let t = (b0 - a0).perp_dot(da) / denom;
+    ((0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t))
+        .then(|| a0 + s * da)
+}
+
+/// Any shape with a deflected plain flap.
+///
+/// Both the panel and the drawing loop are deflected; the analytic
+/// surfaces of the undeflected shape are only passed through while the
+/// flap is neutral.
+#[derive(Clone, Debug)]
+pub struct Flapped<A> {
+    pub shape: A,
+    pub flap: FlapParams,
+}
+
+impl<A> Flapped<A> {
+    pub fn new(shape: A, flap: FlapParams) -> Self {
+        Self { shape, flap }
+    }
+}
+
+impl<A: AirfoilShape> AirfoilShape for Flapped<A> {
+    fn name(&self) -> String {
+        if self.flap.is_neutral() {
+            self.shape.name()
+        } else {
+            format!(
+                "{} (flap {:+.1}°)",
+                self.shape.name(),
+                self.flap.deflection_deg
+            )
+        }
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        deflect_flap(&self.shape.panel_points(), &self.flap)
+    }
+
+    fn body_points(&self) -> Vec<Vec2> {
+        deflect_flap(&self.shape.body_points(), &self.flap)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        if self.flap.is_neutral() {
+            self.shape.analytic_surfaces(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::{naca_loop, validate_loop};
+
+    #[test]
+    fn deflected_loop_is_valid_and_rotates_the_te() {
+        let pts = naca_loop("0012");
+        for deflection_deg in [-20.0, 10.0, 30.0] {
+            let params = FlapParams {
+                deflection_deg,
+                ..Default::default()
+            };
+            let out = deflect_flap(&pts, &params);
+            validate_loop(&out).unwrap_or_else(|err| {
+                panic!("{deflection_deg}°: {err}")
+            });
+
+            // The TE swings through the deflection angle about the hinge.
+            let hinge = params.hinge_point(&pts).unwrap();
+            let te = 0.5 * (out[0] + out[out.len() - 2]);
+            let arm = te - hinge;
+            let turned = (-arm.y).atan2(arm.x).to_degrees();
+            assert!(
+                (turned - deflection_deg).abs() < 0.5,
+                "{deflection_deg}° turned {turned}°"
+            );
+            // The part ahead of the hinge is untouched.
+            for p in pts.iter().filter(|p| p.x < 0.7) {
+                assert!(out.contains(p), "{deflection_deg}°: lost {p}");
+            }
+        }
+    }
+
+    #[test]
+    fn hinge_gap_is_filled_and_overlap_trimmed() {
+        let pts = naca_loop("0012");
+        let params = FlapParams {
+            deflection_deg: 20.0,
+            ..Default::default()
+        };
+        let hinge = params.hinge_point(&pts).unwrap();
+        assert!(hinge.y.abs() < 1e-5);
+        let out = deflect_flap(&pts, &params);
+
+        // Upper surface: arc points at the hinge radius.
+        let radius = 0.5 * Surfaces::from_loop(&pts).thickness(0.75);
+        let on_arc = out
+            .iter()
+            .filter(|p| p.y > 0.0)
+            .filter(|p| (p.distance(hinge) - radius).abs() < 1e-4)
+            .count();
+        assert!(on_arc >= 3, "{on_arc} arc points");
+        // Lower surface: nothing left below the original contour near
+        // the hinge, where the fixed and flap skins used to overlap.
+        let skin = Surfaces::from_loop(&pts);
+        for p in out.iter().filter(|p| p.y < 0.0) {
+            if (0.7..0.75).contains(&p.x) {
+                assert!(p.y >= skin.lower_y(p.x) - 1e-4, "{p}");
+            }
+        }
+    }
+}
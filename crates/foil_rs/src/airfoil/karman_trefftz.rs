@@ -0,0 +1,338 @@
+//! Kármán–Trefftz and Joukowski airfoils, with their exact inviscid flow.
+//!
+//! A circle through ζ = 1 enclosing ζ = -1 is mapped by
+//!
+//! `z = n (1 + q^n) / (1 - q^n)`, with `q = (ζ - 1) / (ζ + 1)`,
+//!
+//! onto an airfoil with a trailing-edge angle of `(2 - n) π`; `n = 2` is
+//! the Joukowski map `z = ζ + 1 / ζ` and gives a cusped TE. The flow past
+//! the circle, with the circulation that puts the rear stagnation point
+//! on ζ = 1, maps onto the exact potential flow past the airfoil, which
+//! makes these shapes ground truth for the panel solvers.
+//!
+//! Shapes are rotated and scaled onto the unit chord, from the LE (the
+//! point farthest from the TE) at the origin to the TE at (1, 0), and α is
+//! measured from that chord line.
+
+use std::f64::consts::PI;
+
+use crate::math::{DVec2, Vec2};
+
+use super::naca::effective_num_points;
+use super::shape::AirfoilShape;
+
+/// Points per surface used for LE searches.
+const LE_SEARCH_SAMPLES: usize = 720;
+
+/// A Kármán–Trefftz airfoil on the unit chord.
+#[derive(Clone, Debug)]
+pub struct KarmanTrefftz {
+    /// Centre of the mapped circle in the ζ-plane. A negative x makes the
+    /// section thicker, a positive y adds camber.
+    pub center: Vec2,
+    /// Included trailing-edge angle; zero gives a Joukowski airfoil.
+    pub te_angle_deg: f32,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+}
+
+impl Default for KarmanTrefftz {
+    fn default() -> Self {
+        // About 12 % thick with 2 % camber.
+        Self::joukowski(Vec2::new(-0.1, 0.04))
+    }
+}
+
+/// Exact flow round a `KarmanTrefftz` section, sampled along its surface.
+#[derive(Clone, Debug)]
+pub struct ExactFlow {
+    /// Surface points in loop order (TE → lower → LE → upper → TE),
+    /// excluding the TE itself, where the map is singular.
+    pub points: Vec<Vec2>,
+    /// Surface speed, positive along the loop (U∞ = 1).
+    pub speed: Vec<f32>,
+    /// Cp = 1 - speed².
+    pub cp: Vec<f32>,
+    /// Lift coefficient from the circulation, CL = 2Γ / (U∞ c).
+    pub cl: f32,
+}
+
+/// The map and the frame that puts its image on the unit chord.
+struct Mapping {
+    n: f64,
+    center: DVec2,
+    radius: f64,
+    /// Circle angle of ζ = 1.
+    te_angle: f64,
+    le: DVec2,
+    chord: f64,
+    /// Angle of the LE → TE line before rotating it onto x.
+    chord_angle: f64,
+}
+
+impl KarmanTrefftz {
+    pub fn new(center: Vec2, te_angle_deg: f32) -> Self {
+        Self {
+            center,
+            te_angle_deg,
+            num_points: 160,
+        }
+    }
+
+    /// A Joukowski airfoil: cusped trailing edge.
+    pub fn joukowski(center: Vec2) -> Self {
+        Self::new(center, 0.0)
+    }
+
+    /// Exact flow at `alpha_deg`, at `samples` points spread evenly round
+    /// the circle.
+    pub fn exact_flow(
+        &self,
+        alpha_deg: f32,
+        samples: usize,
+    ) -> ExactFlow {
+        let map = self.mapping();
+        let alpha = map.circle_alpha(alpha_deg);
+        let gamma = map.circulation(alpha);
+        let samples = samples.max(8);
+        let mut flow = ExactFlow {
+            points: Vec::with_capacity(samples),
+            speed: Vec::with_capacity(samples),
+            cp: Vec::with_capacity(samples),
+            cl: (2.0 * gamma / map.chord) as f32,
+        };
+        for k in 0..samples {
+            let t = (k as f64 + 0.5) / samples as f64;
+            let (point, speed) = map.surface_flow(t, alpha, gamma);
+            flow.points.push(point.as_vec2());
+            flow.speed.push(speed as f32);
+            flow.cp.push((1.0 - speed * speed) as f32);
+        }
+        flow
+    }
+
+    /// Exact lift coefficient at `alpha_deg`.
+    pub fn exact_cl(&self, alpha_deg: f32) -> f32 {
+        let map = self.mapping();
+        let gamma = map.circulation(map.circle_alpha(alpha_deg));
+        (2.0 * gamma / map.chord) as f32
+    }
+
+    fn mapping(&self) -> Mapping {
+        let center = self.center.as_dvec2();
+        let to_te = DVec2::X - center;
+        let mut map = Mapping {
+            n: 2.0 - (self.te_angle_deg as f64).to_radians() / PI,
+            center,
+            radius: to_te.length(),
+            te_angle: to_te.y.atan2(to_te.x),
+            le: DVec2::ZERO,
+            chord: 1.0,
+            chord_angle: 0.0,
+        };
+
+        // LE: the point farthest from the TE, refined by golden section.
+        let te = DVec2::new(map.n, 0.0);
+        let distance = |t: f64| map.raw_point(t).distance_squared(te);
+        let step = 0.5 / LE_SEARCH_SAMPLES as f64;
+        let coarse = (1..2 * LE_SEARCH_SAMPLES)
+            .map(|k| k as f64 * step)
+            .max_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .unwrap_or(0.5);
+        let (mut lo, mut hi) = (coarse - step, coarse + step);
+        let ratio = 0.5 * (5f64.sqrt() - 1.0);
+        for _ in 0..60 {
+            let a = hi - ratio * (hi - lo);
+            let b = lo + ratio * (hi - lo);
+            if distance(a) > distance(b) {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+        map.le = map.raw_point(0.5 * (lo + hi));
+        let chord = te - map.le;
+        map.chord = chord.length();
+        map.chord_angle = chord.y.atan2(chord.x);
+        map
+    }
+}
+
+impl Mapping {
+    /// Point on the circle a fraction `t` of the way round it, clockwise
+    /// from the TE.
+    fn zeta(&self, t: f64) -> DVec2 {
+        let angle = self.te_angle - 2.0 * PI * t;
+        self.center + self.radius * DVec2::from_angle(angle)
+    }
+
+    fn raw_point(&self, t: f64) -> DVec2 {
+        let zeta = self.zeta(t);
+        let r = cpow(cdiv(zeta - DVec2::X, zeta + DVec2::X), self.n);
+        self.n * cdiv(DVec2::X + r, DVec2::X - r)
+    }
+
+    /// Onto the unit chord.
+    fn normalize(&self, z: DVec2) -> DVec2 {
+        DVec2::from_angle(-self.chord_angle).rotate(z - self.le)
+            / self.chord
+    }
+
+    /// Freestream angle in the ζ-plane for `alpha_deg` from the chord.
+    fn circle_alpha(&self, alpha_deg: f32) -> f64 {
+        (alpha_deg as f64).to_radians() + self.chord_angle
+    }
+
+    /// Circulation (clockwise) that satisfies the Kutta condition.
+    fn circulation(&self, alpha: f64) -> f64 {
+        4.0 * PI * self.radius * (alpha - self.te_angle).sin()
+    }
+
+    /// Surface point on the unit chord, and the speed there along the
+    /// loop.
+    fn surface_flow(
+        &self,
+        t: f64,
+        alpha: f64,
+        gamma: f64,
+    ) -> (DVec2, f64) {
+        let zeta = self.zeta(t);
+        let s = zeta - self.center;
+        let free = DVec2::from_angle(-alpha);
+        let doublet = self.radius
+            * self.radius
+            * cdiv(DVec2::from_angle(alpha), cmul(s, s));
+        let vortex = cdiv(DVec2::new(0.0, gamma / (2.0 * PI)), s);
+        let dw_dzeta = free - doublet + vortex;
+
+        let r = cpow(cdiv(zeta - DVec2::X, zeta + DVec2::X), self.n);
+        let one_minus = DVec2::X - r;
+        let dz_dzeta = cdiv(
+            4.0 * self.n * self.n * r,
+            cmul(
+                cmul(one_minus, one_minus),
+                cmul(zeta, zeta) - DVec2::X,
+            ),
+        );
+        // Complex velocity u - iv, then the velocity itself.
+        let w = cdiv(dw_dzeta, dz_dzeta);
+        let velocity = DVec2::new(w.x, -w.y);
+        // The loop runs clockwise round the circle.
+        let tangent = -cmul(dz_dzeta, DVec2::new(0.0, 1.0).rotate(s));
+        let speed = velocity.dot(tangent.normalize_or_zero());
+        (
+            self.normalize(self.n * cdiv(DVec2::X + r, one_minus)),
+            speed,
+        )
+    }
+}
+
+fn cmul(a: DVec2, b: DVec2) -> DVec2 {
+    a.rotate(b)
+}
+
+fn cdiv(a: DVec2, b: DVec2) -> DVec2 {
+    a.rotate(DVec2::new(b.x, -b.y)) / b.length_squared()
+}
+
+/// Principal power; the arguments met here stay clear of the branch cut.
+fn cpow(a: DVec2, n: f64) -> DVec2 {
+    a.length().powf(n) * DVec2::from_angle(n * a.y.atan2(a.x))
+}
+
+/// Loop in solver ordering with `num_points` points per surface, evenly
+/// spaced round the circle.
+pub fn build_karman_trefftz_geometry(
+    shape: &KarmanTrefftz,
+) -> Vec<Vec2> {
+    let map = shape.mapping();
+    let segments = 2 * (shape.num_points.max(8) - 1);
+    let mut points: Vec<Vec2> = (0..segments)
+        .map(|k| {
+            let t = k as f64 / segments as f64;
+            map.normalize(map.raw_point(t)).as_vec2()
+        })
+        .collect();
+    // The TE lands on (1, 0) up to rounding; close on it exactly.
+    points[0] = Vec2::X;
+    points.push(Vec2::X);
+    points
+}
+
+impl AirfoilShape for KarmanTrefftz {
+    fn name(&self) -> String {
+        let kind = if self.te_angle_deg == 0.0 {
+            "Joukowski"
+        } else {
+            "Kármán–Trefftz"
+        };
+        format!("{kind} ({:.3}, {:.3})", self.center.x, self.center.y)
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_karman_trefftz_geometry(&local)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::GeometryProperties;
+
+    #[test]
+    fn symmetric_joukowski_has_textbook_lift() {
+        // Thickness ≈ 1.3 ε for a circle offset by ε = 0.1 / 1.1, and
+        // CL = 8π R / c · sin α, a little above 2π α.
+        let shape = KarmanTrefftz::joukowski(Vec2::new(-0.1, 0.0));
+        let props =
+            GeometryProperties::from_loop(&shape.panel_points())
+                .expect("properties");
+        assert!(
+            props.max_thickness > 0.10 && props.max_thickness < 0.13,
+            "thickness {}",
+            props.max_thickness
+        );
+        assert!(props.max_camber.abs() < 1e-4);
+
+        let cl = shape.exact_cl(5.0);
+        let flow = shape.exact_flow(5.0, 400);
+        assert!((flow.cl - cl).abs() < 1e-6);
+        let map = shape.mapping();
+        let expected =
+            8.0 * PI * map.radius / map.chord * 5f64.to_radians().sin();
+        assert!(
+            (cl as f64 - expected).abs() < 1e-5,
+            "{cl} vs {expected}"
+        );
+        assert!(shape.exact_cl(0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn exact_flow_stagnates_at_the_nose_and_meets_kutta() {
+        let shape = KarmanTrefftz::new(Vec2::new(-0.08, 0.05), 10.0);
+        let points = shape.panel_points();
+        assert_eq!(points.first(), points.last());
+        assert!(
+            points.iter().all(|p| p.x > -1e-4 && p.x < 1.0 + 1e-4),
+            "not on the unit chord"
+        );
+
+        let flow = shape.exact_flow(3.0, 800);
+        // One front stagnation point, where the speed changes sign.
+        let stagnations = flow
+            .speed
+            .windows(2)
+            .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+            .count();
+        assert_eq!(stagnations, 1);
+        assert!(flow.cp.iter().all(|&cp| cp <= 1.0 + 1e-4));
+        // Kutta: both surfaces leave the TE at the same speed.
+        let n = flow.speed.len();
+        let (lower, upper) = (flow.speed[0], flow.speed[n - 1]);
+        assert!(lower < 0.0 && upper > 0.0);
+        assert!((upper + lower).abs() < 0.02, "{lower} vs {upper}");
+        assert!(flow.cl > 0.0);
+    }
+}
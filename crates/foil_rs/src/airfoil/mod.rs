@@ -1,6 +1,74 @@
+pub mod blend;
+pub mod cst;
+pub mod dat;
+pub mod flap;
+pub mod karman_trefftz;
+pub mod modify;
 pub mod naca;
+pub mod naca4_modified;
+pub mod naca5;
+pub mod naca6;
+pub mod naca_section;
+pub mod normalize;
+pub mod parsec;
+pub mod properties;
+pub mod repanel;
+pub mod shape;
+mod spline;
+pub mod surfaces;
+pub mod validate;
 
+pub use blend::{Blended, blend_loops};
+pub use cst::{CstAirfoil, build_cst_geometry};
+pub use dat::{
+    DatAirfoil, DatError, DatFormat, DatWriteOptions, load_dat,
+    parse_dat, save_dat, write_dat,
+};
+pub use flap::{FlapParams, Flapped, HingeY, deflect_flap};
+pub use karman_trefftz::{
+    ExactFlow, KarmanTrefftz, build_karman_trefftz_geometry,
+};
+pub use modify::{
+    ThicknessCamberEdit, scale_le_radius, scale_thickness_camber,
+    set_te_gap,
+};
 pub use naca::{
     build_naca_body_geometry, build_naca_body_geometry_sharp_te,
     camber_line, camber_slope, thickness_distribution,
 };
+pub use naca_section::NacaSection;
+pub use naca4_modified::{
+    Naca4ModifiedParams, build_naca4_modified_geometry,
+};
+pub use naca5::{Naca5Params, build_naca5_geometry};
+pub use naca6::{Naca6Params, Naca6Series, build_naca6_geometry};
+pub use normalize::{NormalizeReport, normalize_loop};
+pub use parsec::{
+    ParsecAirfoil, ParsecError, ParsecParams, build_parsec_geometry,
+};
+pub use properties::GeometryProperties;
+pub use repanel::{PanelingParams, Repaneled, repanel};
+pub use shape::{Airfoil, AirfoilShape};
+pub(crate) use spline::{dedup_points, surface_run};
+pub use surfaces::Surfaces;
+pub use validate::{GeometryError, GeometryWarning, validate_loop};
+
+/// Sharp-TE NACA 4-digit loop in solver ordering, shared by the tests.
+#[cfg(test)]
+pub(crate) fn naca_loop(code: &str) -> Vec<crate::math::Vec2> {
+    build_naca_body_geometry_sharp_te(
+        &crate::state::NacaParams::from_naca4(code).unwrap(),
+    )
+}
+
+/// `naca_loop` with `num_points` nodes per surface.
+#[cfg(test)]
+pub(crate) fn naca_loop_with_points(
+    code: &str,
+    num_points: usize,
+) -> Vec<crate::math::Vec2> {
+    let mut params =
+        crate::state::NacaParams::from_naca4(code).unwrap();
+    params.num_points = num_points;
+    build_naca_body_geometry_sharp_te(&params)
+}
@@ -0,0 +1,335 @@
+//! Shape edits on coordinate loops, after XFoil's `GDES` commands.
+//!
+//! - `set_te_gap` (`TGAP`): opens or closes the trailing edge, spreading
+//!   the change over a blending distance ahead of the TE.
+//! - `scale_le_radius` (`LERAD`): scales the leading-edge radius by
+//!   thickening or thinning the nose, fading out over a blend distance.
+//! - `scale_thickness_camber` (`TSET`/`HIGH`): scales the thickness and
+//!   camber distributions and optionally moves their maxima.
+//!
+//! All of them take and return closed loops in solver ordering.
+
+use crate::math::Vec2;
+
+use super::properties::scan_max;
+use super::spline::{close_run, surface_run};
+use super::surfaces::Surfaces;
+
+/// Exponent cap for the blending functions (`exp(-15)` is negligible).
+const MAX_BLEND_ARG: f32 = 15.0;
+
+/// Chord frame of a loop: leading edge, unit chord direction and length.
+struct ChordFrame {
+    le: Vec2,
+    axis: Vec2,
+    chord: f32,
+}
+
+impl ChordFrame {
+    fn of(run: &[Vec2]) -> Option<Self> {
+        let te = 0.5 * (run[0] + run[run.len() - 1]);
+        let le = run
+            .iter()
+            .copied()
+            .max_by(|a, b| a.distance(te).total_cmp(&b.distance(te)))?;
+        let chord = le.distance(te);
+        (chord > f32::EPSILON).then(|| Self {
+            le,
+            axis: (te - le) / chord,
+            chord,
+        })
+    }
+
+    /// Chordwise position as a fraction of the chord.
+    fn x_over_c(&self, p: Vec2) -> f32 {
+        (p - self.le).dot(self.axis) / self.chord
+    }
+}
+
+/// Set the trailing-edge gap to `gap` (in loop units).
+///
+/// The two surfaces are moved apart along the current gap direction (or
+/// normal to the chord for a sharp TE). The shift is full at the TE and
+/// decays towards the LE; `blend` (0..1 of the chord) sets how far forward
+/// it reaches, 0 moving only the TE points.
+pub fn set_te_gap(points: &[Vec2], gap: f32, blend: f32) -> Vec<Vec2> {
+    let (run, _) = surface_run(points);
+    let Some(frame) = ChordFrame::of(run) else {
+        return points.to_vec();
+    };
+    if run.len() < 5 {
+        return points.to_vec();
+    }
+    let (te_lower, te_upper) = (run[0], run[run.len() - 1]);
+    let current = te_lower.distance(te_upper);
+    let dir = if current > 1e-6 * frame.chord {
+        (te_upper - te_lower) / current
+    } else {
+        frame.axis.perp()
+    };
+    let delta = gap.max(0.0) - current;
+    let blend = blend.clamp(0.0, 1.0);
+    let le = le_index(run, &frame);
+
+    let mut out: Vec<Vec2> = run
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let xoc = frame.x_over_c(p).clamp(0.0, 1.0);
+            let tfac = if blend <= 0.0 {
+                if xoc >= 1.0 - 1e-6 { 1.0 } else { 0.0 }
+            } else {
+                let arg = ((1.0 - xoc) * (1.0 / blend - 1.0))
+                    .min(MAX_BLEND_ARG);
+                (-arg).exp()
+            };
+            let shift = 0.5 * delta * xoc * tfac * dir;
+            if i >= le { p + shift } else { p - shift }
+        })
+        .collect();
+    close_run(&mut out);
+    out
+}
+
+/// Scale the leading-edge radius by `factor`.
+///
+/// The thickness about the local camber line is multiplied by
+/// `1 - (1 - √factor) exp(-x/blend)`, so the radius (∝ thickness²) scales
+/// by `factor` and the change fades out over `blend` (fraction of chord).
+pub fn scale_le_radius(
+    points: &[Vec2],
+    factor: f32,
+    blend: f32,
+) -> Vec<Vec2> {
+    let (run, _) = surface_run(points);
+    let Some(frame) = ChordFrame::of(run) else {
+        return points.to_vec();
+    };
+    if run.len() < 5 {
+        return points.to_vec();
+    }
+    let root = factor.abs().sqrt();
+    let blend = blend.max(1e-4);
+
+    // Work in the chord frame so thickness is measured normal to it.
+    let local: Vec<Vec2> = run
+        .iter()
+        .map(|&p| {
+            let d = p - frame.le;
+            Vec2::new(d.dot(frame.axis), frame.axis.perp_dot(d))
+                / frame.chord
+        })
+        .collect();
+    let surfaces = Surfaces::from_loop(&local);
+
+    let mut out: Vec<Vec2> = local
+        .iter()
+        .map(|&p| {
+            let arg = (p.x.max(0.0) / blend).min(MAX_BLEND_ARG);
+            let tfac = 1.0 - (1.0 - root) * (-arg).exp();
+            let camber = surfaces.camber(p.x);
+            let q = Vec2::new(p.x, camber + (p.y - camber) * tfac);
+            frame.le
+                + frame.chord
+                    * (q.x * frame.axis + q.y * frame.axis.perp())
+        })
+        .collect();
+    close_run(&mut out);
+    out
+}
+
+/// Thickness and camber changes for `scale_thickness_camber`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThicknessCamberEdit {
+    /// Factor on the thickness distribution (0.15 / 0.12 turns a 12%
+    /// section into a 15% one).
+    pub thickness_scale: f32,
+    /// Factor on the camber line.
+    pub camber_scale: f32,
+    /// New chordwise location of maximum thickness (x/c), if moved.
+    pub max_thickness_x: Option<f32>,
+    /// New chordwise location of maximum camber (x/c), if moved.
+    pub max_camber_x: Option<f32>,
+}
+
+impl Default for ThicknessCamberEdit {
+    fn default() -> Self {
+        Self {
+            thickness_scale: 1.0,
+            camber_scale: 1.0,
+            max_thickness_x: None,
+            max_camber_x: None,
+        }
+    }
+}
+
+/// Scale the thickness and camber of a section independently.
+///
+/// The loop is split at its leading edge (minimum x) into a camber line
+/// and a thickness distribution, both measured in y at each x like
+/// `camber_line` and `thickness_distribution` describe a NACA section.
+/// Each is scaled and, when a new maximum location is given, stretched
+/// piecewise-linearly along the chord so its maximum lands there; the
+/// surfaces are then rebuilt at the original x stations. Works on loops
+/// with the chord along x, as `normalize_loop` leaves them.
+pub fn scale_thickness_camber(
+    points: &[Vec2],
+    edit: &ThicknessCamberEdit,
+) -> Vec<Vec2> {
+    let (run, _) = surface_run(points);
+    if run.len() < 5 {
+        return points.to_vec();
+    }
+    let le = run
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+        .map_or(0, |(i, _)| i);
+    let surfaces = Surfaces {
+        upper: run[le..].to_vec(),
+        lower: run[..=le].iter().rev().copied().collect(),
+    };
+    let x_le = run[le].x;
+    let chord = 0.5 * (run[0].x + run[run.len() - 1].x) - x_le;
+    if chord <= f32::EPSILON {
+        return points.to_vec();
+    }
+    // Maxima and stretching in x/c.
+    let at = |x_c: f32| x_le + x_c * chord;
+    let thickness_map = edit.max_thickness_x.map(|to| {
+        let (from, _) =
+            scan_max(0.0, 1.0, |x| surfaces.thickness(at(x)));
+        (from, to)
+    });
+    let camber_map = edit.max_camber_x.map(|to| {
+        let (from, _) =
+            scan_max(0.0, 1.0, |x| surfaces.camber(at(x)).abs());
+        (from, to)
+    });
+
+    let mut out: Vec<Vec2> = run
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let x_c = (p.x - x_le) / chord;
+            let half = 0.5
+                * edit.thickness_scale
+                * surfaces.thickness(at(stretch(x_c, thickness_map)));
+            let camber = edit.camber_scale
+                * surfaces.camber(at(stretch(x_c, camber_map)));
+            let y = if i >= le {
+                camber + half
+            } else {
+                camber - half
+            };
+            Vec2::new(p.x, y)
+        })
+        .collect();
+    close_run(&mut out);
+    out
+}
+
+/// Chord station of the original distribution that lands on `x` once
+/// its maximum moves `from → to`: linear on each side of the maximum.
+fn stretch(x: f32, map: Option<(f32, f32)>) -> f32 {
+    let Some((from, to)) = map else {
+        return x;
+    };
+    let to = to.clamp(0.01, 0.99);
+    if x <= to {
+        x * from / to
+    } else {
+        from + (x - to) * (1.0 - from) / (1.0 - to)
+    }
+}
+
+/// Index of the leading edge (the point farthest from the TE).
+fn le_index(run: &[Vec2], frame: &ChordFrame) -> usize {
+    run.iter()
+        .position(|&p| p == frame.le)
+        .unwrap_or(run.len() / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::{
+        GeometryProperties, naca_loop, validate_loop,
+    };
+
+    #[test]
+    fn te_gap_opens_and_closes() {
+        let pts = naca_loop("2412");
+        let base = GeometryProperties::from_loop(&pts).unwrap();
+
+        let thick = set_te_gap(&pts, 0.02, 0.8);
+        validate_loop(&thick).expect("blunt loop");
+        let props = GeometryProperties::from_loop(&thick).unwrap();
+        assert!((props.te_thickness - 0.02).abs() < 1e-5);
+        assert!(
+            (props.max_thickness - base.max_thickness).abs() < 5e-3,
+            "{} vs {}",
+            props.max_thickness,
+            base.max_thickness
+        );
+
+        let sharp = set_te_gap(&pts, 0.0, 0.8);
+        validate_loop(&sharp).expect("sharp loop");
+        assert_eq!(sharp.len(), pts.len() - 1);
+        assert_eq!(sharp[0], sharp[sharp.len() - 1]);
+        let props = GeometryProperties::from_loop(&sharp).unwrap();
+        assert!(props.te_thickness < 1e-6);
+
+        // Opening a sharp TE again appends the closing point.
+        let reopened = set_te_gap(&sharp, 0.01, 0.5);
+        assert_eq!(reopened.len(), pts.len());
+    }
+
+    #[test]
+    fn le_radius_scales_with_factor() {
+        let pts = naca_loop("0012");
+        let base = GeometryProperties::from_loop(&pts).unwrap();
+        for factor in [0.5, 2.0] {
+            let out = scale_le_radius(&pts, factor, 0.1);
+            validate_loop(&out).expect("valid loop");
+            let props = GeometryProperties::from_loop(&out).unwrap();
+            let ratio = props.le_radius / base.le_radius;
+            assert!(
+                (ratio - factor).abs() < 0.15 * factor,
+                "factor {factor}: radius ratio {ratio}"
+            );
+            // The aft section is left alone.
+            assert!(
+                (props.te_thickness - base.te_thickness).abs() < 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn thickness_and_camber_scale_and_move() {
+        let pts = naca_loop("2412");
+        let base = GeometryProperties::from_loop(&pts).unwrap();
+        let same = scale_thickness_camber(&pts, &Default::default());
+        assert_eq!(same.len(), pts.len());
+        for (a, b) in same.iter().zip(&pts) {
+            assert!(a.distance(*b) < 1e-5, "{a} vs {b}");
+        }
+
+        let edit = ThicknessCamberEdit {
+            thickness_scale: 0.15 / 0.12,
+            camber_scale: 0.5,
+            max_thickness_x: Some(0.4),
+            max_camber_x: Some(0.3),
+        };
+        let out = scale_thickness_camber(&pts, &edit);
+        validate_loop(&out).expect("valid loop");
+        let props = GeometryProperties::from_loop(&out).unwrap();
+        assert!((props.max_thickness - 0.15).abs() < 2e-3);
+        assert!((props.max_thickness_x - 0.4).abs() < 0.02);
+        assert!(
+            (props.max_camber - 0.5 * base.max_camber).abs() < 2e-4
+        );
+        assert!((props.max_camber_x - 0.3).abs() < 0.02);
+        assert_eq!(out[0], out[out.len() - 1]);
+    }
+}
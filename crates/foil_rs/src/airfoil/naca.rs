@@ -3,6 +3,8 @@ use std::f32::consts::PI;
 use crate::math::Vec2;
 use crate::state::NacaParams;
 
+use super::shape::AirfoilShape;
+
 /// Build NACA 4-digit geometry in body coordinates as a closed loop.
 ///
 /// Ordering: starts at the trailing edge, goes along the lower surface to the
@@ -107,55 +109,34 @@ pub fn build_naca_body_geometry(params: &NacaParams) -> Vec<Vec2> {
 pub fn build_naca_body_geometry_sharp_te(
     params: &NacaParams,
 ) -> Vec<Vec2> {
-    let m = params.m();
-    let p = params.p();
-    let t = params.t();
-    let n = params.num_points.max(32);
-
-    let mut upper: Vec<Vec2> = Vec::with_capacity(n);
-    let mut lower: Vec<Vec2> = Vec::with_capacity(n);
-    let mut full: Vec<Vec2> = Vec::with_capacity(2 * n + 1);
-
-    for i in (0..n).rev() {
-        let beta = i as f32 / (n - 1) as f32;
-        let x_c = 0.5 * (1.0 - (PI * beta).cos());
-
-        let camber = camber_line(m, p, x_c);
-        let slope = camber_slope(m, p, x_c);
-        let theta = slope.atan();
-        let thickness = thickness_distribution(t, x_c);
-
-        let x_u = x_c - thickness * theta.sin();
-        let y_u = camber + thickness * theta.cos();
-
-        upper.push(Vec2::new(x_u, y_u));
-    }
-
-    for i in 0..n {
-        let beta = i as f32 / (n - 1) as f32;
-        let x_c = 0.5 * (1.0 - (PI * beta).cos());
-
-        let camber = camber_line(m, p, x_c);
-        let slope = camber_slope(m, p, x_c);
-        let theta = slope.atan();
-        let thickness = thickness_distribution(t, x_c);
-
-        let x_l = x_c + thickness * theta.sin();
-        let y_l = camber - thickness * theta.cos();
+    let (m, p, t) = (params.m(), params.p(), params.t());
+    sharp_te_loop(params.num_points, |x_c| {
+        naca_surface_points(m, p, t, x_c)
+    })
+}
 
-        lower.push(Vec2::new(x_l, y_l));
-    }
+/// Closed sharp-TE loop from a section's surface points at chord station
+/// `x_c`, sampled at `num_points` cosine-spaced stations per surface.
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`. Shared by all the
+/// NACA families so they discretize identically.
+pub(crate) fn sharp_te_loop(
+    num_points: usize,
+    surface: impl Fn(f32) -> (Vec2, Vec2),
+) -> Vec<Vec2> {
+    let n = num_points.max(32);
+    let mut full: Vec<Vec2> = Vec::with_capacity(2 * n);
 
-    if upper.is_empty() || lower.is_empty() {
-        return full;
-    }
+    let stations: Vec<(Vec2, Vec2)> = (0..n)
+        .map(|i| {
+            let beta = i as f32 / (n - 1) as f32;
+            surface(0.5 * (1.0 - (PI * beta).cos()))
+        })
+        .collect();
 
-    for pt in lower.iter().rev() {
-        full.push(*pt);
-    }
-    for pt in upper.iter().rev().skip(1) {
-        full.push(*pt);
-    }
+    // Lower surface TE → LE, then upper surface LE → TE.
+    full.extend(stations.iter().rev().map(|(_, lower)| *lower));
+    full.extend(stations.iter().skip(1).map(|(upper, _)| *upper));
 
     if let Some(&first) = full.first() {
         full.push(first);
@@ -191,3 +172,65 @@ pub fn thickness_distribution(t: f32, x: f32) -> f32 {
             + 0.2843 * x * x * x
             - 0.1015 * x * x * x * x)
 }
+
+/// Upper and lower surface points at chord station `x_c`.
+pub(crate) fn naca_surface_points(
+    m: f32,
+    p: f32,
+    t: f32,
+    x_c: f32,
+) -> (Vec2, Vec2) {
+    thickened_surface_points(
+        x_c,
+        camber_line(m, p, x_c),
+        camber_slope(m, p, x_c),
+        thickness_distribution(t, x_c),
+    )
+}
+
+/// Lay `thickness` off perpendicular to a mean line with height `camber`
+/// and slope `slope` at `x_c`, as all NACA families do.
+pub(crate) fn thickened_surface_points(
+    x_c: f32,
+    camber: f32,
+    slope: f32,
+    thickness: f32,
+) -> (Vec2, Vec2) {
+    let theta = slope.atan();
+    let upper = Vec2::new(
+        x_c - thickness * theta.sin(),
+        camber + thickness * theta.cos(),
+    );
+    let lower = Vec2::new(
+        x_c + thickness * theta.sin(),
+        camber - thickness * theta.cos(),
+    );
+    (upper, lower)
+}
+
+/// The panel solver wants an even number of points per surface so odd and
+/// even slider values produce the same discretization.
+pub(crate) fn effective_num_points(num_points: usize) -> usize {
+    let n = num_points.max(32);
+    if n.is_multiple_of(2) { n } else { n + 1 }
+}
+
+impl AirfoilShape for NacaParams {
+    fn name(&self) -> String {
+        format!("NACA {}", self.code())
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_naca_body_geometry_sharp_te(&local)
+    }
+
+    fn body_points(&self) -> Vec<Vec2> {
+        build_naca_body_geometry(self)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        Some(naca_surface_points(self.m(), self.p(), self.t(), x))
+    }
+}
@@ -0,0 +1,324 @@
+//! NACA modified 4-digit sections (e.g. `0012-64`, `2412-34`).
+//!
+//! The suffix `-IT` sets the leading-edge radius index `I` (6 = the
+//! standard 4-digit radius, 0 = sharp, 9 = three times standard) and the
+//! position of maximum thickness `T` in tenths of chord. The thickness
+//! form is the usual two-piece polynomial: `√x` plus cubic ahead of the
+//! maximum, cubic in `(1 - x)` behind it, matched in value, slope and
+//! curvature at the maximum.
+
+use crate::math::Vec2;
+
+use super::naca::{
+    camber_line, camber_slope, effective_num_points, sharp_te_loop,
+    thickened_surface_points,
+};
+use super::shape::AirfoilShape;
+
+/// Trailing-edge half-angle coefficient `d1` for `T = 2..=6`.
+const TE_SLOPE: [f32; 5] = [0.200, 0.234, 0.315, 0.465, 0.700];
+
+/// Parameters for a NACA modified 4-digit airfoil.
+///
+/// The suffix digits are private so the thickness form can be built once
+/// and only from a valid pair; use [`Naca4ModifiedParams::new`] or
+/// [`Naca4ModifiedParams::from_naca4_modified`].
+#[derive(Clone, Debug)]
+pub struct Naca4ModifiedParams {
+    /// First digit: maximum camber in % of chord.
+    pub m_digit: f32,
+    /// Second digit: position of max camber in tenths of chord.
+    pub p_digit: f32,
+    /// Third and fourth digits: thickness in % of chord.
+    pub t_digits: f32,
+    /// Leading-edge radius index (0–9, 6 = standard 4-digit).
+    le_radius_index: u8,
+    /// Position of maximum thickness in tenths of chord (2–6).
+    max_thickness_digit: u8,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+    form: ThicknessForm,
+}
+
+impl Default for Naca4ModifiedParams {
+    fn default() -> Self {
+        // NACA 0012-64
+        Self {
+            m_digit: 0.0,
+            p_digit: 0.0,
+            t_digits: 12.0,
+            le_radius_index: 6,
+            max_thickness_digit: 4,
+            num_points: 160,
+            form: ThicknessForm::new(6, 4),
+        }
+    }
+}
+
+/// Coefficients of the two-piece half-thickness polynomial, normalized so
+/// the maximum is 0.1 (multiply by `5·t`).
+#[derive(Clone, Debug)]
+struct ThicknessForm {
+    x_max: f32,
+    a: [f32; 4],
+    d: [f32; 4],
+}
+
+impl ThicknessForm {
+    fn new(le_radius_index: u8, max_thickness_digit: u8) -> Self {
+        let x_max = 0.1 * max_thickness_digit as f32;
+        let d1 =
+            TE_SLOPE[usize::from(max_thickness_digit.clamp(2, 6) - 2)];
+        let a0 = if le_radius_index >= 9 {
+            0.296904 * 3f32.sqrt()
+        } else {
+            0.296904 * le_radius_index as f32 / 6.0
+        };
+
+        // Aft cubic: y(1) = 0.002, y'(1) = -d1, y(m) = 0.1, y'(m) = 0.
+        let d0 = 0.002;
+        let s = 1.0 - x_max;
+        let rest = 0.1 - d0 - d1 * s;
+        let d2 = (3.0 * rest + d1 * s) / (s * s);
+        let d3 = (rest - d2 * s * s) / (s * s * s);
+
+        // Forward part: value, slope and curvature continuity at x_max.
+        let m = x_max;
+        let curvature_aft = 2.0 * d2 + 6.0 * d3 * s;
+        let r1 = (0.1 - a0 * m.sqrt()) / m;
+        let r2 = -a0 / (2.0 * m.sqrt());
+        let r3 = curvature_aft + a0 / (4.0 * m * m.sqrt());
+        let q = (r2 - r1) / m;
+        let a3 = (0.5 * r3 - q) / m;
+        let a2 = q - 2.0 * m * a3;
+        let a1 = r1 - m * a2 - m * m * a3;
+
+        Self {
+            x_max,
+            a: [a0, a1, a2, a3],
+            d: [d0, d1, d2, d3],
+        }
+    }
+
+    fn eval(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        if x <= self.x_max {
+            let [a0, a1, a2, a3] = self.a;
+            a0 * x.sqrt() + a1 * x + a2 * x * x + a3 * x * x * x
+        } else {
+            let [d0, d1, d2, d3] = self.d;
+            let s = 1.0 - x;
+            d0 + d1 * s + d2 * s * s + d3 * s * s * s
+        }
+    }
+}
+
+impl Naca4ModifiedParams {
+    /// Build a section from its digits.
+    ///
+    /// Returns `None` unless the leading-edge radius index is 0–9 and the
+    /// maximum-thickness position is 2–6.
+    pub fn new(
+        m_digit: f32,
+        p_digit: f32,
+        t_digits: f32,
+        le_radius_index: u8,
+        max_thickness_digit: u8,
+    ) -> Option<Self> {
+        if le_radius_index > 9
+            || !(2..=6).contains(&max_thickness_digit)
+        {
+            return None;
+        }
+        Some(Self {
+            m_digit,
+            p_digit,
+            t_digits,
+            le_radius_index,
+            max_thickness_digit,
+            form: ThicknessForm::new(
+                le_radius_index,
+                max_thickness_digit,
+            ),
+            ..Self::default()
+        })
+    }
+
+    /// Leading-edge radius index (0–9, 6 = standard 4-digit).
+    pub fn le_radius_index(&self) -> u8 {
+        self.le_radius_index
+    }
+    /// Position of maximum thickness in tenths of chord (2–6).
+    pub fn max_thickness_digit(&self) -> u8 {
+        self.max_thickness_digit
+    }
+    /// Maximum camber (m) in chord fractions.
+    pub fn m(&self) -> f32 {
+        self.m_digit / 100.0
+    }
+    /// Position of maximum camber (p) in chord fractions.
+    pub fn p(&self) -> f32 {
+        self.p_digit / 10.0
+    }
+    /// Thickness (t) in chord fractions.
+    pub fn t(&self) -> f32 {
+        self.t_digits / 100.0
+    }
+    /// Position of maximum thickness in chord fractions.
+    pub fn max_thickness_position(&self) -> f32 {
+        0.1 * self.max_thickness_digit as f32
+    }
+    pub fn code(&self) -> String {
+        // e.g. "0012-64"
+        format!(
+            "{:.0}{:.0}{:02.0}-{}{}",
+            self.m_digit,
+            self.p_digit,
+            self.t_digits,
+            self.le_radius_index,
+            self.max_thickness_digit
+        )
+    }
+
+    /// Parse a modified 4-digit code (e.g. `"0012-64"`).
+    ///
+    /// Returns `None` unless the code is 4 digits, a dash and 2 digits
+    /// with the maximum-thickness position in 2–6.
+    pub fn from_naca4_modified(code: &str) -> Option<Self> {
+        let (base, suffix) = code.trim().split_once('-')?;
+        let base = base.as_bytes();
+        let suffix = suffix.as_bytes();
+        if base.len() != 4 || suffix.len() != 2 {
+            return None;
+        }
+        if !base.iter().chain(suffix).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Self::new(
+            (base[0] - b'0') as f32,
+            (base[1] - b'0') as f32,
+            ((base[2] - b'0') as u16 * 10 + (base[3] - b'0') as u16)
+                as f32,
+            suffix[0] - b'0',
+            suffix[1] - b'0',
+        )
+    }
+
+    /// Half thickness at `x` in chord fractions.
+    pub fn thickness_distribution(&self, x: f32) -> f32 {
+        5.0 * self.t() * self.form.eval(x)
+    }
+
+    /// Upper and lower surface points at chord station `x_c`.
+    pub fn surface_points(&self, x_c: f32) -> (Vec2, Vec2) {
+        let (m, p) = (self.m(), self.p());
+        thickened_surface_points(
+            x_c,
+            camber_line(m, p, x_c),
+            camber_slope(m, p, x_c),
+            self.thickness_distribution(x_c),
+        )
+    }
+}
+
+/// Build NACA modified 4-digit geometry as a closed loop with a sharp
+/// trailing edge.
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`.
+pub fn build_naca4_modified_geometry(
+    params: &Naca4ModifiedParams,
+) -> Vec<Vec2> {
+    sharp_te_loop(params.num_points, |x_c| params.surface_points(x_c))
+}
+
+impl AirfoilShape for Naca4ModifiedParams {
+    fn name(&self) -> String {
+        format!("NACA {}", self.code())
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_naca4_modified_geometry(&local)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        Some(self.surface_points(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modified_codes() {
+        let p = Naca4ModifiedParams::from_naca4_modified("0012-64")
+            .expect("0012-64");
+        assert_eq!(p.le_radius_index, 6);
+        assert_eq!(p.max_thickness_digit, 4);
+        assert_eq!(p.code(), "0012-64");
+
+        let p = Naca4ModifiedParams::from_naca4_modified(" 2412-34 ")
+            .expect("2412-34");
+        assert_eq!(p.m(), 0.02);
+
+        for bad in [
+            "0012", "0012-6", "0012-67", "0012-60", "012-64", "0012-x4",
+        ] {
+            assert!(
+                Naca4ModifiedParams::from_naca4_modified(bad).is_none(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn thickness_peaks_at_requested_position() {
+        for digit in 2..=6 {
+            let params =
+                Naca4ModifiedParams::new(0.0, 0.0, 12.0, 6, digit)
+                    .unwrap();
+            let (x_max, y_max) = (0..=1000)
+                .map(|i| {
+                    let x = i as f32 / 1000.0;
+                    (x, params.thickness_distribution(x))
+                })
+                .fold((0.0, 0.0), |a, p| if p.1 > a.1 { p } else { a });
+            let target = params.max_thickness_position();
+            assert!(
+                (x_max - target).abs() < 0.02,
+                "T={digit}: {x_max}"
+            );
+            assert!((2.0 * y_max - 0.12).abs() < 1e-4, "T={digit}");
+            let te = params.thickness_distribution(1.0);
+            assert!((te - 0.01 * 0.12).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn le_radius_index_scales_nose_thickness() {
+        let at_nose = |index| {
+            Naca4ModifiedParams::new(0.0, 0.0, 12.0, index, 4)
+                .unwrap()
+                .thickness_distribution(1e-4)
+        };
+        let ratio = at_nose(3) / at_nose(6);
+        assert!((ratio - 0.5).abs() < 0.02, "ratio={ratio}");
+        assert!(at_nose(0) < 0.1 * at_nose(6));
+    }
+
+    #[test]
+    fn rejects_digits_outside_the_tabulated_range() {
+        for t in [0, 1, 7] {
+            assert!(
+                Naca4ModifiedParams::new(0.0, 0.0, 12.0, 6, t)
+                    .is_none()
+            );
+        }
+        assert!(
+            Naca4ModifiedParams::new(0.0, 0.0, 12.0, 10, 4).is_none()
+        );
+    }
+}
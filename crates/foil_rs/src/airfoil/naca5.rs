@@ -0,0 +1,318 @@
+//! NACA 5-digit sections (standard and reflexed mean lines).
+//!
+//! A code `LPQTT` reads as: design lift coefficient `0.15·L`, position of
+//! maximum camber `0.05·P`, `Q = 1` for the reflexed mean line and
+//! thickness `TT` % chord. The mean-line constants below are the published
+//! values for a design CL of 0.3 (`L = 2`) and scale linearly with `L`.
+//! Thickness uses the 4-digit distribution.
+
+use crate::math::Vec2;
+
+use super::naca::{
+    effective_num_points, sharp_te_loop, thickened_surface_points,
+    thickness_distribution,
+};
+use super::shape::AirfoilShape;
+
+/// Standard mean line `(r, k1)` for `P = 1..=5`.
+const STANDARD: [(f32, f32); 5] = [
+    (0.0580, 361.400),
+    (0.1260, 51.640),
+    (0.2025, 15.957),
+    (0.2900, 6.643),
+    (0.3910, 3.230),
+];
+
+/// Reflexed mean line `(r, k1, k2/k1)` for `P = 2..=5`.
+#[allow(clippy::approx_constant)] // r = 0.3180 is tabulated, not 1/π.
+const REFLEX: [(f32, f32, f32); 4] = [
+    (0.1300, 51.990, 0.000764),
+    (0.2170, 15.793, 0.00677),
+    (0.3180, 6.520, 0.0303),
+    (0.4410, 3.191, 0.1355),
+];
+
+/// Parameters for a NACA 5-digit airfoil.
+#[derive(Clone, Debug)]
+pub struct Naca5Params {
+    /// First digit: design lift coefficient in units of 0.15.
+    pub design_cl_digit: u8,
+    /// Second digit: position of max camber in units of 0.05 chord.
+    pub camber_pos_digit: u8,
+    /// Third digit: reflexed mean line (`1`) or standard (`0`).
+    pub reflex: bool,
+    /// Last two digits: thickness in % of chord.
+    pub t_digits: f32,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+}
+
+impl Default for Naca5Params {
+    fn default() -> Self {
+        // NACA 23012
+        Self {
+            design_cl_digit: 2,
+            camber_pos_digit: 3,
+            reflex: false,
+            t_digits: 12.0,
+            num_points: 160,
+        }
+    }
+}
+
+/// Mean-line constants scaled to the section's design CL.
+struct MeanLine {
+    r: f32,
+    k1: f32,
+    /// `k2 / k1`; `None` for the standard (non-reflexed) line.
+    k21: Option<f32>,
+}
+
+impl Naca5Params {
+    /// Design lift coefficient.
+    pub fn design_cl(&self) -> f32 {
+        0.15 * self.design_cl_digit as f32
+    }
+    /// Nominal position of maximum camber in chord fractions.
+    pub fn camber_position(&self) -> f32 {
+        0.05 * self.camber_pos_digit as f32
+    }
+    /// Thickness (t) in chord fractions.
+    pub fn t(&self) -> f32 {
+        self.t_digits / 100.0
+    }
+    pub fn code(&self) -> String {
+        // e.g. "23012"
+        format!(
+            "{}{}{}{:02.0}",
+            self.design_cl_digit,
+            self.camber_pos_digit,
+            u8::from(self.reflex),
+            self.t_digits
+        )
+    }
+
+    /// Parse a 5-digit NACA code (e.g. `"23012"`, `"25112"`).
+    ///
+    /// Returns `None` unless the string is 5 ASCII digits whose camber
+    /// position has published constants (`P = 1..=5`, or `2..=5` when
+    /// reflexed) and whose third digit is `0` or `1`.
+    pub fn from_naca5(code: &str) -> Option<Self> {
+        let code = code.trim();
+        let bytes = code.as_bytes();
+        if bytes.len() != 5 {
+            return None;
+        }
+        if !bytes.iter().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let digit = |i: usize| bytes[i] - b'0';
+        let reflex = match digit(2) {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        let params = Self {
+            design_cl_digit: digit(0),
+            camber_pos_digit: digit(1),
+            reflex,
+            t_digits: (digit(3) as u16 * 10 + digit(4) as u16) as f32,
+            ..Self::default()
+        };
+        params.mean_line().map(|_| params)
+    }
+
+    fn mean_line(&self) -> Option<MeanLine> {
+        let scale = self.design_cl() / 0.3;
+        let idx = usize::from(self.camber_pos_digit);
+        if self.reflex {
+            let &(r, k1, k21) = REFLEX.get(idx.checked_sub(2)?)?;
+            Some(MeanLine {
+                r,
+                k1: k1 * scale,
+                k21: Some(k21),
+            })
+        } else {
+            let &(r, k1) = STANDARD.get(idx.checked_sub(1)?)?;
+            Some(MeanLine {
+                r,
+                k1: k1 * scale,
+                k21: None,
+            })
+        }
+    }
+
+    /// Mean-line height at `x`. Camber positions without published
+    /// constants give a flat mean line.
+    pub fn camber_line(&self, x: f32) -> f32 {
+        let Some(MeanLine { r, k1, k21 }) = self.mean_line() else {
+            return 0.0;
+        };
+        let r3 = r * r * r;
+        match k21 {
+            None if x < r => {
+                k1 / 6.0
+                    * (x * x * x - 3.0 * r * x * x
+                        + r * r * (3.0 - r) * x)
+            }
+            None => k1 * r3 / 6.0 * (1.0 - x),
+            Some(k21) => {
+                let tail = k21 * (1.0 - r).powi(3) * x + r3 * x - r3;
+                let cubic = if x < r {
+                    (x - r).powi(3)
+                } else {
+                    k21 * (x - r).powi(3)
+                };
+                k1 / 6.0 * (cubic - tail)
+            }
+        }
+    }
+
+    /// Mean-line slope `dy/dx` at `x`.
+    pub fn camber_slope(&self, x: f32) -> f32 {
+        let Some(MeanLine { r, k1, k21 }) = self.mean_line() else {
+            return 0.0;
+        };
+        let r3 = r * r * r;
+        match k21 {
+            None if x < r => {
+                k1 / 6.0
+                    * (3.0 * x * x - 6.0 * r * x + r * r * (3.0 - r))
+            }
+            None => -k1 * r3 / 6.0,
+            Some(k21) => {
+                let tail = k21 * (1.0 - r).powi(3) + r3;
+                let cubic = if x < r {
+                    3.0 * (x - r).powi(2)
+                } else {
+                    3.0 * k21 * (x - r).powi(2)
+                };
+                k1 / 6.0 * (cubic - tail)
+            }
+        }
+    }
+
+    /// Upper and lower surface points at chord station `x_c`.
+    pub fn surface_points(&self, x_c: f32) -> (Vec2, Vec2) {
+        thickened_surface_points(
+            x_c,
+            self.camber_line(x_c),
+            self.camber_slope(x_c),
+            thickness_distribution(self.t(), x_c),
+        )
+    }
+}
+
+/// Build NACA 5-digit geometry as a closed loop with a sharp trailing edge.
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`.
+pub fn build_naca5_geometry(params: &Naca5Params) -> Vec<Vec2> {
+    sharp_te_loop(params.num_points, |x_c| params.surface_points(x_c))
+}
+
+impl AirfoilShape for Naca5Params {
+    fn name(&self) -> String {
+        format!("NACA {}", self.code())
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_naca5_geometry(&local)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        Some(self.surface_points(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_standard_and_reflexed_codes() {
+        let std = Naca5Params::from_naca5("23012").expect("23012");
+        assert_eq!(std.design_cl_digit, 2);
+        assert_eq!(std.camber_pos_digit, 3);
+        assert!(!std.reflex);
+        assert_eq!(std.code(), "23012");
+
+        let reflex = Naca5Params::from_naca5(" 25112 ").expect("25112");
+        assert!(reflex.reflex);
+        assert_eq!(reflex.t(), 0.12);
+
+        for bad in ["2412", "23512", "26012", "21112", "2301a"] {
+            assert!(
+                Naca5Params::from_naca5(bad).is_none(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn naca230_mean_line_matches_published_max_camber() {
+        let params = Naca5Params::default();
+        let (x_max, y_max) = (0..=1000)
+            .map(|i| {
+                let x = i as f32 / 1000.0;
+                (x, params.camber_line(x))
+            })
+            .fold(
+                (0.0, 0.0),
+                |acc, p| if p.1 > acc.1 { p } else { acc },
+            );
+
+        // Published: 1.83 % camber at 15 % chord.
+        assert!((y_max - 0.0183).abs() < 0.0005, "y_max={y_max}");
+        assert!((x_max - 0.15).abs() < 0.01, "x_max={x_max}");
+    }
+
+    #[test]
+    fn mean_lines_are_closed_and_continuous() {
+        for code in ["23012", "25012", "22112", "25112"] {
+            let params = Naca5Params::from_naca5(code).unwrap();
+            let r = params.mean_line().unwrap().r;
+            assert!(params.camber_line(0.0).abs() < 1e-6, "{code}");
+            assert!(params.camber_line(1.0).abs() < 1e-5, "{code}");
+            let gap = params.camber_line(r - 1e-4)
+                - params.camber_line(r + 1e-4);
+            assert!(gap.abs() < 1e-4, "{code}: jump {gap} at r");
+
+            let h = 1e-3;
+            let x = 0.6;
+            let fd = (params.camber_line(x + h)
+                - params.camber_line(x - h))
+                / (2.0 * h);
+            assert!(
+                (fd - params.camber_slope(x)).abs() < 1e-3,
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn reflexed_line_unloads_the_trailing_edge() {
+        let standard = Naca5Params::from_naca5("25012").unwrap();
+        let reflex = Naca5Params::from_naca5("25112").unwrap();
+        assert!(
+            reflex.camber_slope(0.98) > standard.camber_slope(0.98)
+        );
+        assert!(reflex.camber_line(0.9) < standard.camber_line(0.9));
+    }
+
+    #[test]
+    fn geometry_uses_solver_loop_ordering() {
+        let pts = build_naca5_geometry(&Naca5Params::default());
+        let first = pts[0];
+        let last = pts[pts.len() - 1];
+        assert_eq!(first, last);
+        assert!((first.x - 1.0).abs() < 1e-4);
+
+        // `num_points` lower points (TE → LE), then the upper surface.
+        let le = Naca5Params::default().num_points - 1;
+        assert!(pts[le].length() < 1e-4, "LE at {:?}", pts[le]);
+        assert!(pts[le / 2].y < pts[le + le / 2].y);
+    }
+}
@@ -0,0 +1,424 @@
+//! NACA 6-series sections (e.g. `63-215`, `64A010`).
+//!
+//! Thickness comes from the tabulated 10 % basic thickness forms, scaled
+//! linearly to the requested thickness (the usual approximation to the
+//! conformal-mapping definition). Camber uses the `a`-family mean line
+//! with the design lift coefficient from the code (`a = 1` unless a
+//! ` a=0.x` suffix is given).
+
+use std::f32::consts::PI;
+use std::sync::LazyLock;
+
+use crate::math::Vec2;
+
+use super::naca::{
+    effective_num_points, sharp_te_loop, thickened_surface_points,
+};
+use super::shape::AirfoilShape;
+
+/// Chord stations (% chord) of the tabulated thickness forms.
+const STATIONS: [f32; 26] = [
+    0.0, 0.5, 0.75, 1.25, 2.5, 5.0, 7.5, 10.0, 15.0, 20.0, 25.0, 30.0,
+    35.0, 40.0, 45.0, 50.0, 55.0, 60.0, 65.0, 70.0, 75.0, 80.0, 85.0,
+    90.0, 95.0, 100.0,
+];
+
+/// `√(x/c)` at `STATIONS`, the abscissa the thickness forms are
+/// interpolated over.
+static SQRT_STATIONS: LazyLock<[f32; 26]> =
+    LazyLock::new(|| STATIONS.map(|x| (0.01 * x).sqrt()));
+
+/// Half thickness (% chord) of the 10 % thick basic forms at `STATIONS`.
+const T63_010: [f32; 26] = [
+    0.0, 0.829, 1.004, 1.275, 1.756, 2.440, 2.950, 3.362, 3.994, 4.445,
+    4.753, 4.938, 5.000, 4.938, 4.766, 4.496, 4.140, 3.715, 3.232,
+    2.712, 2.166, 1.618, 1.088, 0.604, 0.214, 0.0,
+];
+const T64_010: [f32; 26] = [
+    0.0, 0.804, 0.969, 1.225, 1.688, 2.327, 2.805, 3.199, 3.813, 4.272,
+    4.606, 4.837, 4.968, 5.000, 4.912, 4.701, 4.385, 3.988, 3.524,
+    3.005, 2.446, 1.862, 1.278, 0.732, 0.250, 0.0,
+];
+const T65_010: [f32; 26] = [
+    0.0, 0.772, 0.932, 1.169, 1.574, 2.177, 2.647, 3.040, 3.666, 4.143,
+    4.503, 4.760, 4.924, 4.996, 4.963, 4.812, 4.530, 4.146, 3.682,
+    3.156, 2.584, 1.987, 1.385, 0.810, 0.306, 0.0,
+];
+const T64A010: [f32; 26] = [
+    0.0, 0.804, 0.969, 1.225, 1.688, 2.327, 2.805, 3.199, 3.813, 4.272,
+    4.606, 4.837, 4.968, 5.000, 4.941, 4.785, 4.530, 4.186, 3.768,
+    3.300, 2.794, 2.265, 1.724, 1.182, 0.640, 0.098,
+];
+
+/// 6-series families with a tabulated thickness form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Naca6Series {
+    S63,
+    S64,
+    S65,
+    S64A,
+}
+
+impl Naca6Series {
+    fn label(self) -> &'static str {
+        match self {
+            Self::S63 => "63",
+            Self::S64 => "64",
+            Self::S65 => "65",
+            Self::S64A => "64A",
+        }
+    }
+
+    fn table(self) -> &'static [f32; 26] {
+        match self {
+            Self::S63 => &T63_010,
+            Self::S64 => &T64_010,
+            Self::S65 => &T65_010,
+            Self::S64A => &T64A010,
+        }
+    }
+}
+
+/// Parameters for a NACA 6-series airfoil.
+#[derive(Clone, Debug)]
+pub struct Naca6Params {
+    pub series: Naca6Series,
+    /// Design lift coefficient of the mean line.
+    pub design_cl: f32,
+    /// Thickness in % of chord.
+    pub t_digits: f32,
+    /// Chordwise extent of uniform loading of the mean line (0–1).
+    pub mean_line_a: f32,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+}
+
+impl Default for Naca6Params {
+    fn default() -> Self {
+        // NACA 63-215
+        Self {
+            series: Naca6Series::S63,
+            design_cl: 0.2,
+            t_digits: 15.0,
+            mean_line_a: 1.0,
+            num_points: 160,
+        }
+    }
+}
+
+impl Naca6Params {
+    /// Thickness (t) in chord fractions.
+    pub fn t(&self) -> f32 {
+        self.t_digits / 100.0
+    }
+
+    pub fn code(&self) -> String {
+        // e.g. "63-215", "64A010"
+        let sep = if self.series == Naca6Series::S64A {
+            ""
+        } else {
+            "-"
+        };
+        let mut code = format!(
+            "{}{}{:.0}{:02.0}",
+            self.series.label(),
+            sep,
+            self.design_cl * 10.0,
+            self.t_digits
+        );
+        if (self.mean_line_a - 1.0).abs() > 1e-6 {
+            code.push_str(&format!(" a={}", self.mean_line_a));
+        }
+        code
+    }
+
+    /// Parse a 6-series code such as `"63-215"`, `"64A010"`,
+    /// `"63(2)-215"`, `"632-215"` or `"65-410 a=0.5"`.
+    ///
+    /// The low-drag range subscript is accepted and ignored. Returns `None`
+    /// for families without a tabulated thickness form.
+    pub fn from_naca6(code: &str) -> Option<Self> {
+        let code = code.trim();
+        let (body, mean_line_a) = match code.split_once("a=") {
+            Some((body, a)) => {
+                let a: f32 = a.trim().parse().ok()?;
+                if !(0.0..=1.0).contains(&a) {
+                    return None;
+                }
+                (body.trim(), a)
+            }
+            None => (code, 1.0),
+        };
+
+        let rest = body.strip_prefix('6')?;
+        let mut chars = rest.chars();
+        let family = chars.next()?;
+        let mut rest = chars.as_str();
+
+        // Optional low-drag range subscript: "(2)", "_2" or "2-".
+        if let Some(r) = rest.strip_prefix('(') {
+            let (digit, r) = r.split_once(')')?;
+            if digit.len() != 1
+                || !digit.chars().all(|c| c.is_ascii_digit())
+            {
+                return None;
+            }
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('_') {
+            rest = r.get(1..)?;
+        } else if rest.len() > 1
+            && rest.as_bytes()[0].is_ascii_digit()
+            && rest.as_bytes()[1] == b'-'
+        {
+            rest = &rest[1..];
+        }
+        let rest = rest.strip_prefix('-').unwrap_or(rest);
+        let (a_series, digits) = match rest.strip_prefix(['A', 'a']) {
+            Some(d) => (true, d),
+            None => (false, rest),
+        };
+
+        let series = match (family, a_series) {
+            ('3', false) => Naca6Series::S63,
+            ('4', false) => Naca6Series::S64,
+            ('5', false) => Naca6Series::S65,
+            ('4', true) => Naca6Series::S64A,
+            _ => return None,
+        };
+
+        let bytes = digits.as_bytes();
+        if bytes.len() != 3 || !bytes.iter().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        Some(Self {
+            series,
+            design_cl: (bytes[0] - b'0') as f32 / 10.0,
+            t_digits: ((bytes[1] - b'0') as u16 * 10
+                + (bytes[2] - b'0') as u16)
+                as f32,
+            mean_line_a,
+            ..Self::default()
+        })
+    }
+
+    /// Half thickness at `x` in chord fractions.
+    pub fn thickness_distribution(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        // Interpolate in √x so the round nose (y ∝ √x) stays smooth.
+        let y = pchip(&*SQRT_STATIONS, self.series.table(), x.sqrt());
+        0.01 * y * self.t() / 0.10
+    }
+
+    /// Mean-line height at `x`.
+    pub fn camber_line(&self, x: f32) -> f32 {
+        a_mean_line(self.design_cl, self.mean_line_a, x).0
+    }
+
+    /// Mean-line slope `dy/dx` at `x`.
+    pub fn camber_slope(&self, x: f32) -> f32 {
+        a_mean_line(self.design_cl, self.mean_line_a, x).1
+    }
+
+    /// Upper and lower surface points at chord station `x_c`.
+    pub fn surface_points(&self, x_c: f32) -> (Vec2, Vec2) {
+        let (camber, slope) =
+            a_mean_line(self.design_cl, self.mean_line_a, x_c);
+        thickened_surface_points(
+            x_c,
+            camber,
+            slope,
+            self.thickness_distribution(x_c),
+        )
+    }
+}
+
+/// `(y, dy/dx)` of the NACA `a`-family mean line.
+///
+/// The slope is logarithmically infinite at the ends, so `x` is kept a
+/// hair inside the chord.
+fn a_mean_line(cl_i: f32, a: f32, x: f32) -> (f32, f32) {
+    if cl_i == 0.0 {
+        return (0.0, 0.0);
+    }
+    let x = x.clamp(1e-6, 1.0 - 1e-6);
+    if a >= 1.0 - 1e-4 {
+        let k = -cl_i / (4.0 * PI);
+        let y = k * (xlnx(1.0 - x) + xlnx(x));
+        let dy = k * (x.ln() - (1.0 - x).ln());
+        return (y, dy);
+    }
+
+    let a = a.max(0.0);
+    let g = -1.0 / (1.0 - a)
+        * (if a > 0.0 {
+            a * a * (0.5 * a.ln() - 0.25)
+        } else {
+            0.0
+        } + 0.25);
+    let h = 1.0 / (1.0 - a)
+        * (0.5 * (1.0 - a) * (1.0 - a) * (1.0 - a).ln()
+            - 0.25 * (1.0 - a) * (1.0 - a))
+        + g;
+    let k = cl_i / (2.0 * PI * (a + 1.0));
+    let am = (a - x).abs();
+    let y = k
+        * (1.0 / (1.0 - a)
+            * (0.5 * am * xlnx(am) - 0.5 * (1.0 - x) * xlnx(1.0 - x)
+                + 0.25 * (1.0 - x) * (1.0 - x)
+                - 0.25 * (a - x) * (a - x))
+            - xlnx(x)
+            + g
+            - h * x);
+    let dy = k
+        * (1.0 / (1.0 - a)
+            * (xlnx(1.0 - x) - (a - x).signum() * xlnx(am))
+            - x.ln()
+            - 1.0
+            - h);
+    (y, dy)
+}
+
+/// `v·ln v` for `v ≥ 0`, zero at the origin.
+fn xlnx(v: f32) -> f32 {
+    if v > 0.0 { v * v.ln() } else { 0.0 }
+}
+
+/// Monotone piecewise-cubic (Fritsch–Carlson) interpolation of `ys` over
+/// increasing `xs`, clamped to the table ends.
+fn pchip(xs: &[f32], ys: &[f32], x: f32) -> f32 {
+    let n = xs.len();
+    let i = xs.partition_point(|&v| v <= x).clamp(1, n - 1) - 1;
+    let h = xs[i + 1] - xs[i];
+    let t = ((x - xs[i]) / h).clamp(0.0, 1.0);
+
+    let slope = |k: usize| (ys[k + 1] - ys[k]) / (xs[k + 1] - xs[k]);
+    let tangent = |k: usize| -> f32 {
+        if k == 0 {
+            return slope(0);
+        }
+        if k == n - 1 {
+            return slope(n - 2);
+        }
+        let (d0, d1) = (slope(k - 1), slope(k));
+        if d0 * d1 <= 0.0 {
+            return 0.0;
+        }
+        let (h0, h1) = (xs[k] - xs[k - 1], xs[k + 1] - xs[k]);
+        let w0 = 2.0 * h1 + h0;
+        let w1 = h1 + 2.0 * h0;
+        (w0 + w1) / (w0 / d0 + w1 / d1)
+    };
+
+    let (m0, m1) = (tangent(i), tangent(i + 1));
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * ys[i]
+        + (t3 - 2.0 * t2 + t) * h * m0
+        + (-2.0 * t3 + 3.0 * t2) * ys[i + 1]
+        + (t3 - t2) * h * m1
+}
+
+/// Build NACA 6-series geometry as a closed loop. The small open
+/// trailing edge of the 6A forms is closed with a straight segment.
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`.
+pub fn build_naca6_geometry(params: &Naca6Params) -> Vec<Vec2> {
+    sharp_te_loop(params.num_points, |x_c| params.surface_points(x_c))
+}
+
+impl AirfoilShape for Naca6Params {
+    fn name(&self) -> String {
+        format!("NACA {}", self.code())
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.num_points = effective_num_points(self.num_points);
+        build_naca6_geometry(&local)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        Some(self.surface_points(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_six_series_codes() {
+        let p = Naca6Params::from_naca6("63-215").expect("63-215");
+        assert_eq!(p.series, Naca6Series::S63);
+        assert!((p.design_cl - 0.2).abs() < 1e-6);
+        assert_eq!(p.t_digits, 15.0);
+        assert_eq!(p.code(), "63-215");
+
+        let p = Naca6Params::from_naca6("64A010").expect("64A010");
+        assert_eq!(p.series, Naca6Series::S64A);
+        assert_eq!(p.design_cl, 0.0);
+        assert_eq!(p.code(), "64A010");
+
+        for code in ["63(2)-215", "632-215", "63_2-215"] {
+            let p = Naca6Params::from_naca6(code).expect(code);
+            assert_eq!(p.code(), "63-215", "{code}");
+        }
+
+        let p = Naca6Params::from_naca6("65-410 a=0.5").expect("a=0.5");
+        assert_eq!(p.mean_line_a, 0.5);
+
+        for bad in ["2412", "73-215", "66-215", "64B010", "63-2150"] {
+            assert!(
+                Naca6Params::from_naca6(bad).is_none(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn thickness_matches_table_and_scales() {
+        let p = Naca6Params::from_naca6("64-010").unwrap();
+        for (&x, &y) in STATIONS.iter().zip(T64_010.iter()) {
+            let got = 100.0 * p.thickness_distribution(0.01 * x);
+            assert!((got - y).abs() < 1e-3, "x={x}: {got} vs {y}");
+        }
+        let thick = Naca6Params::from_naca6("63-215").unwrap();
+        let max = (0..=200)
+            .map(|i| thick.thickness_distribution(i as f32 / 200.0))
+            .fold(0.0, f32::max);
+        assert!((2.0 * max - 0.15).abs() < 1e-3, "max={max}");
+    }
+
+    #[test]
+    fn uniform_load_mean_line_has_published_peak() {
+        // a = 1: y_max = cl_i ln 2 / (4π) at mid-chord.
+        let p = Naca6Params::from_naca6("63-215").unwrap();
+        let expected = 0.2 * 2f32.ln() / (4.0 * PI);
+        assert!((p.camber_line(0.5) - expected).abs() < 1e-5);
+        assert!(p.camber_slope(0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn partial_load_mean_lines_close_and_match_slope() {
+        for a in [0.0, 0.5, 0.8] {
+            let p = Naca6Params {
+                mean_line_a: a,
+                design_cl: 0.4,
+                ..Default::default()
+            };
+            assert!(p.camber_line(0.0).abs() < 1e-4, "a={a}");
+            assert!(p.camber_line(1.0).abs() < 1e-4, "a={a}");
+            for x in [0.2, 0.6, 0.9] {
+                let h = 1e-3;
+                let fd = (p.camber_line(x + h) - p.camber_line(x - h))
+                    / (2.0 * h);
+                let dy = p.camber_slope(x);
+                assert!(
+                    (fd - dy).abs() < 2e-3,
+                    "a={a} x={x}: {fd} {dy}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::math::Vec2;
+use crate::state::NacaParams;
+
+use super::naca4_modified::Naca4ModifiedParams;
+use super::naca5::Naca5Params;
+use super::naca6::Naca6Params;
+use super::shape::AirfoilShape;
+
+/// Any NACA section we can generate, selected by its designation.
+#[derive(Clone, Debug)]
+pub enum NacaSection {
+    Four(NacaParams),
+    FourModified(Naca4ModifiedParams),
+    Five(Naca5Params),
+    Six(Naca6Params),
+}
+
+impl NacaSection {
+    /// Parse a NACA designation, with or without a `"NACA"` prefix.
+    ///
+    /// Tries 4-digit, modified 4-digit, 5-digit and 6-series forms in
+    /// that order, e.g. `"2412"`, `"0012-64"`, `"23012"`, `"64A010"`.
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = code.trim();
+        let code = code
+            .strip_prefix("NACA")
+            .or_else(|| code.strip_prefix("naca"))
+            .unwrap_or(code)
+            .trim();
+
+        NacaParams::from_naca4(code)
+            .map(Self::Four)
+            .or_else(|| {
+                Naca4ModifiedParams::from_naca4_modified(code)
+                    .map(Self::FourModified)
+            })
+            .or_else(|| Naca5Params::from_naca5(code).map(Self::Five))
+            .or_else(|| Naca6Params::from_naca6(code).map(Self::Six))
+    }
+
+    /// The designation without the `"NACA "` prefix.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Four(p) => p.code(),
+            Self::FourModified(p) => p.code(),
+            Self::Five(p) => p.code(),
+            Self::Six(p) => p.code(),
+        }
+    }
+
+    fn shape(&self) -> &dyn AirfoilShape {
+        match self {
+            Self::Four(p) => p,
+            Self::FourModified(p) => p,
+            Self::Five(p) => p,
+            Self::Six(p) => p,
+        }
+    }
+}
+
+impl AirfoilShape for NacaSection {
+    fn name(&self) -> String {
+        self.shape().name()
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        self.shape().panel_points()
+    }
+
+    fn body_points(&self) -> Vec<Vec2> {
+        self.shape().body_points()
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        self.shape().analytic_surfaces(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dispatches_on_designation() {
+        let cases = [
+            ("2412", "2412"),
+            ("NACA 0012-64", "0012-64"),
+            ("naca23012", "23012"),
+            ("63-215", "63-215"),
+            ("64A010", "64A010"),
+        ];
+        for (input, code) in cases {
+            let section = NacaSection::parse(input).expect(input);
+            assert_eq!(section.code(), code);
+            assert_eq!(section.name(), format!("NACA {code}"));
+        }
+        assert!(matches!(
+            NacaSection::parse("25112"),
+            Some(NacaSection::Five(p)) if p.reflex
+        ));
+        assert!(NacaSection::parse("NACA 12").is_none());
+    }
+}
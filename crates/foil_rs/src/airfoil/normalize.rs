@@ -0,0 +1,210 @@
+//! Normalization of raw coordinate loops (XFoil `NORM`).
+//!
+//! Imported coordinates may come with an arbitrary chord, an offset or
+//! rotated chord line, repeated points and either orientation. The loop is
+//! brought to the form the solvers expect: leading edge at the origin,
+//! trailing-edge midpoint at `(1, 0)`, TE → lower → LE → upper ordering
+//! and a closing point that repeats the first.
+
+use std::fmt;
+
+use crate::math::{Vec2, signed_area};
+
+use super::shape::Airfoil;
+use super::spline::{LoopSpline, dedup_points};
+
+/// What `normalize_loop` had to change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NormalizeReport {
+    /// Consecutive points dropped as duplicates (not counting the closing
+    /// point).
+    pub duplicates_removed: usize,
+    /// The input ran TE → upper → LE → lower and was reversed.
+    pub reversed: bool,
+    /// The input was open and a closing point was appended.
+    pub closed: bool,
+    /// Original leading edge (the point farthest from the TE midpoint).
+    pub leading_edge: Vec2,
+    /// Original chord length; coordinates were divided by it.
+    pub chord: f32,
+    /// Original chord-line angle, counter-clockwise from +x, in degrees.
+    /// The loop was rotated by the opposite amount.
+    pub chord_angle_deg: f32,
+}
+
+impl Default for NormalizeReport {
+    fn default() -> Self {
+        Self {
+            duplicates_removed: 0,
+            reversed: false,
+            closed: false,
+            leading_edge: Vec2::ZERO,
+            chord: 1.0,
+            chord_angle_deg: 0.0,
+        }
+    }
+}
+
+impl NormalizeReport {
+    /// Whether the loop differs from the input beyond round-off.
+    pub fn changed(&self) -> bool {
+        self.duplicates_removed > 0
+            || self.reversed
+            || self.closed
+            || self.leading_edge.length() > 1e-5
+            || (self.chord - 1.0).abs() > 1e-5
+            || self.chord_angle_deg.abs() > 1e-3
+    }
+}
+
+impl fmt::Display for NormalizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.changed() {
+            return write!(f, "already normalized");
+        }
+        let mut parts: Vec<String> = Vec::new();
+        if self.duplicates_removed > 0 {
+            parts.push(format!(
+                "removed {} duplicate point(s)",
+                self.duplicates_removed
+            ));
+        }
+        if self.reversed {
+            parts.push("reversed ordering".to_string());
+        }
+        if self.closed {
+            parts.push("closed the loop".to_string());
+        }
+        if self.leading_edge.length() > 1e-5 {
+            parts.push(format!(
+                "moved LE from ({:.5}, {:.5})",
+                self.leading_edge.x, self.leading_edge.y
+            ));
+        }
+        if (self.chord - 1.0).abs() > 1e-5 {
+            parts.push(format!("scaled chord {:.5} to 1", self.chord));
+        }
+        if self.chord_angle_deg.abs() > 1e-3 {
+            parts.push(format!(
+                "derotated by {:.3}°",
+                self.chord_angle_deg
+            ));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Normalize a loop that starts at the trailing edge (either surface).
+///
+/// Loops with fewer than three distinct points are returned unchanged
+/// with a default report.
+pub fn normalize_loop(points: &[Vec2]) -> (Vec<Vec2>, NormalizeReport) {
+    let extent = points
+        .iter()
+        .fold(0.0f32, |acc, p| acc.max(p.x.abs()).max(p.y.abs()))
+        .max(f32::MIN_POSITIVE);
+    let tol = 1e-6 * extent;
+
+    let mut open = points;
+    let was_closed = matches!(
+        points,
+        [first, .., last] if first.distance(*last) <= tol
+    );
+    if was_closed {
+        open = &points[..points.len() - 1];
+    }
+    let mut pts = dedup_points(open, tol);
+    if pts.len() < 3 {
+        return (points.to_vec(), NormalizeReport::default());
+    }
+    let mut report = NormalizeReport {
+        duplicates_removed: open.len() - pts.len(),
+        closed: !was_closed,
+        ..Default::default()
+    };
+
+    // TE → lower → LE → upper runs clockwise (negative area).
+    if signed_area(&pts) > 0.0 {
+        pts.reverse();
+        report.reversed = true;
+    }
+
+    let first = pts[0];
+    let last = pts[pts.len() - 1];
+    let te = 0.5 * (first + last);
+    let spline = LoopSpline::new(&pts);
+    let le = spline.point(spline.farthest_from(te));
+    let chord_vec = te - le;
+    let chord = chord_vec.length();
+    if chord <= tol {
+        return (points.to_vec(), NormalizeReport::default());
+    }
+    report.leading_edge = le;
+    report.chord = chord;
+    report.chord_angle_deg =
+        chord_vec.y.atan2(chord_vec.x).to_degrees();
+
+    // Rotate the chord line onto +x and scale it to unit length.
+    let rot = Vec2::new(chord_vec.x, -chord_vec.y) / (chord * chord);
+    let mut out: Vec<Vec2> =
+        pts.iter().map(|&p| rot.rotate(p - le)).collect();
+    if first.distance(last) > tol {
+        out.push(out[0]);
+    }
+    (out, report)
+}
+
+impl Airfoil {
+    /// Copy of this airfoil with its loop normalized.
+    pub fn normalized(&self) -> (Self, NormalizeReport) {
+        let (points, report) = normalize_loop(&self.points);
+        (Self::new(self.name.clone(), points), report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::naca_loop;
+
+    #[test]
+    fn normalized_loop_is_left_alone() {
+        let pts = naca_loop("0012");
+        let (out, report) = normalize_loop(&pts);
+        assert!(!report.changed(), "{report}");
+        assert_eq!(out.len(), pts.len());
+        for (a, b) in out.iter().zip(&pts) {
+            assert!(a.distance(*b) < 1e-5);
+        }
+    }
+
+    #[test]
+    fn undoes_scale_rotation_offset_and_ordering() {
+        let reference = normalize_loop(&naca_loop("2412")).0;
+
+        // Open, reversed, scaled, rotated, shifted, with a repeated point.
+        let angle = 10f32.to_radians();
+        let rot = Vec2::new(angle.cos(), angle.sin());
+        let mut raw: Vec<Vec2> = reference[..reference.len() - 1]
+            .iter()
+            .rev()
+            .map(|&p| rot.rotate(p * 3.0) + Vec2::new(5.0, -2.0))
+            .collect();
+        raw.insert(10, raw[10]);
+
+        let (out, report) = normalize_loop(&raw);
+        assert_eq!(report.duplicates_removed, 1);
+        assert!(report.reversed && report.closed);
+        assert!((report.chord - 3.0).abs() < 1e-4);
+        assert!((report.chord_angle_deg - 10.0).abs() < 1e-3);
+        assert!(
+            report.leading_edge.distance(Vec2::new(5.0, -2.0)) < 1e-4
+        );
+
+        assert_eq!(out.len(), reference.len());
+        for (a, b) in out.iter().zip(&reference) {
+            assert!(a.distance(*b) < 1e-4, "{a} vs {b}");
+        }
+        assert!(report.to_string().contains("reversed ordering"));
+    }
+}
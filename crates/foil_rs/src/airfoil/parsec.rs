@@ -0,0 +1,412 @@
+//! PARSEC airfoils (Sobieczky).
+//!
+//! Each surface is `y(x) = Σ aₙ · x^(n - 1/2)` for `n = 1..=6`, with the
+//! six coefficients fixed by the leading-edge radius, the crest position,
+//! height and curvature, and the trailing-edge height and slope. The
+//! 11-parameter form shares one LE radius between the surfaces; the
+//! 12-parameter form sets them separately.
+
+use std::fmt;
+
+use crate::math::{Vec2, solve_dense};
+
+use super::naca::{effective_num_points, sharp_te_loop};
+use super::shape::AirfoilShape;
+
+/// PARSEC design parameters on the unit chord (angles in radians).
+#[derive(Clone, Debug)]
+pub struct ParsecParams {
+    /// Leading-edge radius of the upper surface.
+    pub r_le_upper: f32,
+    /// Leading-edge radius of the lower surface (equal to `r_le_upper` in
+    /// the 11-parameter form).
+    pub r_le_lower: f32,
+    /// Upper crest position, height and curvature `y''`.
+    pub x_up: f32,
+    pub y_up: f32,
+    pub yxx_up: f32,
+    /// Lower crest position, height and curvature `y''`.
+    pub x_lo: f32,
+    pub y_lo: f32,
+    pub yxx_lo: f32,
+    /// Trailing-edge mid-point height.
+    pub y_te: f32,
+    /// Trailing-edge thickness.
+    pub te_thickness: f32,
+    /// Trailing-edge direction (mean of the two surface angles), positive
+    /// when the trailing edge turns upward.
+    pub te_direction: f32,
+    /// Trailing-edge wedge angle between the surfaces.
+    pub te_wedge: f32,
+    /// Sampling points per surface (for geometry).
+    pub num_points: usize,
+}
+
+impl Default for ParsecParams {
+    fn default() -> Self {
+        // Close to NACA 0012.
+        Self::with_le_radius(
+            0.0155, 0.30, 0.06, -0.45, 0.30, -0.06, 0.45, 0.0, 0.0,
+            0.0, 0.27,
+        )
+    }
+}
+
+/// Errors reported when PARSEC parameters do not describe a valid section.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsecError {
+    /// A parameter is outside its admissible range.
+    InvalidParameter(&'static str),
+    /// The linear system for a surface is singular.
+    Singular,
+    /// The upper surface dips below the lower one at `x`.
+    SurfacesCross { x: f32 },
+}
+
+impl fmt::Display for ParsecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidParameter(name) => {
+                write!(f, "PARSEC parameter out of range: {name}")
+            }
+            Self::Singular => {
+                write!(f, "PARSEC surface system is singular")
+            }
+            Self::SurfacesCross { x } => {
+                write!(
+                    f,
+                    "upper and lower surfaces cross at x = {x:.4}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsecError {}
+
+impl ParsecParams {
+    /// 11-parameter form: one leading-edge radius for both surfaces.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_le_radius(
+        r_le: f32,
+        x_up: f32,
+        y_up: f32,
+        yxx_up: f32,
+        x_lo: f32,
+        y_lo: f32,
+        yxx_lo: f32,
+        y_te: f32,
+        te_thickness: f32,
+        te_direction: f32,
+        te_wedge: f32,
+    ) -> Self {
+        Self {
+            r_le_upper: r_le,
+            r_le_lower: r_le,
+            x_up,
+            y_up,
+            yxx_up,
+            x_lo,
+            y_lo,
+            yxx_lo,
+            y_te,
+            te_thickness,
+            te_direction,
+            te_wedge,
+            num_points: 160,
+        }
+    }
+
+    /// Solve both surfaces and check the section does not self-intersect.
+    pub fn build(&self) -> Result<ParsecAirfoil, ParsecError> {
+        self.check_ranges()?;
+
+        let upper = solve_surface(
+            self.r_le_upper.sqrt() * std::f32::consts::SQRT_2,
+            self.x_up,
+            self.y_up,
+            self.yxx_up,
+            self.y_te + 0.5 * self.te_thickness,
+            (self.te_direction - 0.5 * self.te_wedge).tan(),
+        )?;
+        let lower = solve_surface(
+            -self.r_le_lower.sqrt() * std::f32::consts::SQRT_2,
+            self.x_lo,
+            self.y_lo,
+            self.yxx_lo,
+            self.y_te - 0.5 * self.te_thickness,
+            (self.te_direction + 0.5 * self.te_wedge).tan(),
+        )?;
+
+        let airfoil = ParsecAirfoil {
+            params: self.clone(),
+            upper,
+            lower,
+        };
+        airfoil.check_thickness()?;
+        Ok(airfoil)
+    }
+
+    /// `Ok` if the parameters describe a valid, non-self-intersecting
+    /// section.
+    pub fn validate(&self) -> Result<(), ParsecError> {
+        self.build().map(|_| ())
+    }
+
+    fn check_ranges(&self) -> Result<(), ParsecError> {
+        let values = [
+            self.r_le_upper,
+            self.r_le_lower,
+            self.x_up,
+            self.y_up,
+            self.yxx_up,
+            self.x_lo,
+            self.y_lo,
+            self.yxx_lo,
+            self.y_te,
+            self.te_thickness,
+            self.te_direction,
+            self.te_wedge,
+        ];
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(ParsecError::InvalidParameter(
+                "non-finite value",
+            ));
+        }
+        if self.r_le_upper <= 0.0 || self.r_le_lower <= 0.0 {
+            return Err(ParsecError::InvalidParameter("r_le"));
+        }
+        if !(0.0 < self.x_up && self.x_up < 1.0) {
+            return Err(ParsecError::InvalidParameter("x_up"));
+        }
+        if !(0.0 < self.x_lo && self.x_lo < 1.0) {
+            return Err(ParsecError::InvalidParameter("x_lo"));
+        }
+        if self.te_thickness < 0.0 {
+            return Err(ParsecError::InvalidParameter("te_thickness"));
+        }
+        if self.te_wedge < 0.0 {
+            return Err(ParsecError::InvalidParameter("te_wedge"));
+        }
+        Ok(())
+    }
+}
+
+/// Exponent of the `n`-th PARSEC term (`n` from 0).
+fn exponent(n: usize) -> f64 {
+    n as f64 + 0.5
+}
+
+/// Coefficients for one surface from its LE term `a1`, crest and TE data.
+fn solve_surface(
+    a1: f32,
+    x_crest: f32,
+    y_crest: f32,
+    yxx_crest: f32,
+    y_te: f32,
+    slope_te: f32,
+) -> Result<[f64; 6], ParsecError> {
+    let xc = x_crest as f64;
+    let value = |x: f64| -> Vec<f64> {
+        (0..6).map(|n| x.powf(exponent(n))).collect()
+    };
+    let slope = |x: f64| -> Vec<f64> {
+        (0..6)
+            .map(|n| exponent(n) * x.powf(exponent(n) - 1.0))
+            .collect()
+    };
+    let curvature: Vec<f64> = (0..6)
+        .map(|n| {
+            let p = exponent(n);
+            p * (p - 1.0) * xc.powf(p - 2.0)
+        })
+        .collect();
+    let mut leading = vec![0.0; 6];
+    leading[0] = 1.0;
+
+    let rows = [
+        leading,
+        value(1.0),
+        value(xc),
+        slope(xc),
+        curvature,
+        slope(1.0),
+    ];
+    let rhs = vec![
+        a1 as f64,
+        y_te as f64,
+        y_crest as f64,
+        0.0,
+        yxx_crest as f64,
+        slope_te as f64,
+    ];
+    let matrix: Vec<f64> = rows.into_iter().flatten().collect();
+    let coeffs =
+        solve_dense(matrix, rhs).ok_or(ParsecError::Singular)?;
+    let mut out = [0.0; 6];
+    out.copy_from_slice(&coeffs);
+    Ok(out)
+}
+
+fn eval(coeffs: &[f64; 6], x: f64) -> f64 {
+    coeffs
+        .iter()
+        .enumerate()
+        .map(|(n, a)| a * x.powf(exponent(n)))
+        .sum()
+}
+
+fn eval_slope(coeffs: &[f64; 6], x: f64) -> f64 {
+    coeffs
+        .iter()
+        .enumerate()
+        .map(|(n, a)| a * exponent(n) * x.powf(exponent(n) - 1.0))
+        .sum()
+}
+
+/// A solved PARSEC section.
+#[derive(Clone, Debug)]
+pub struct ParsecAirfoil {
+    pub params: ParsecParams,
+    upper: [f64; 6],
+    lower: [f64; 6],
+}
+
+impl ParsecAirfoil {
+    /// `(upper, lower)` surface heights at `x`.
+    pub fn surfaces(&self, x: f32) -> (f32, f32) {
+        let x = x.clamp(0.0, 1.0) as f64;
+        (eval(&self.upper, x) as f32, eval(&self.lower, x) as f32)
+    }
+
+    /// `(upper, lower)` surface slopes `dy/dx` at `x` (kept a hair off
+    /// the nose, where the slope is infinite).
+    pub fn slopes(&self, x: f32) -> (f32, f32) {
+        let x = (x as f64).clamp(1e-9, 1.0);
+        (
+            eval_slope(&self.upper, x) as f32,
+            eval_slope(&self.lower, x) as f32,
+        )
+    }
+
+    /// Coefficients `a₁..a₆` of the upper and lower surfaces.
+    pub fn coefficients(&self) -> ([f64; 6], [f64; 6]) {
+        (self.upper, self.lower)
+    }
+
+    fn check_thickness(&self) -> Result<(), ParsecError> {
+        const SAMPLES: usize = 400;
+        for i in 1..SAMPLES {
+            let x = i as f32 / SAMPLES as f32;
+            let (yu, yl) = self.surfaces(x);
+            if !(yu.is_finite() && yl.is_finite()) || yu <= yl {
+                return Err(ParsecError::SurfacesCross { x });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Build a PARSEC airfoil as a closed loop (straight closing segment
+/// across a finite trailing edge).
+///
+/// Ordering matches `build_naca_body_geometry_sharp_te`.
+pub fn build_parsec_geometry(airfoil: &ParsecAirfoil) -> Vec<Vec2> {
+    sharp_te_loop(airfoil.params.num_points, |x| {
+        let (yu, yl) = airfoil.surfaces(x);
+        (Vec2::new(x, yu), Vec2::new(x, yl))
+    })
+}
+
+impl AirfoilShape for ParsecAirfoil {
+    fn name(&self) -> String {
+        "PARSEC".to_string()
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut local = self.clone();
+        local.params.num_points =
+            effective_num_points(self.params.num_points);
+        build_parsec_geometry(&local)
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        let (yu, yl) = self.surfaces(x);
+        Some((Vec2::new(x, yu), Vec2::new(x, yl)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solved_surfaces_meet_their_constraints() {
+        let params = ParsecParams {
+            r_le_lower: 0.006,
+            y_te: -0.005,
+            te_thickness: 0.002,
+            te_direction: -0.08,
+            ..ParsecParams::default()
+        };
+        let airfoil = params.build().expect("valid section");
+
+        let (yu, _) = airfoil.surfaces(params.x_up);
+        let (_, yl) = airfoil.surfaces(params.x_lo);
+        assert!((yu - params.y_up).abs() < 1e-5);
+        assert!((yl - params.y_lo).abs() < 1e-5);
+        let (su, _) = airfoil.slopes(params.x_up);
+        let (_, sl) = airfoil.slopes(params.x_lo);
+        assert!(su.abs() < 1e-4 && sl.abs() < 1e-4);
+
+        let (yu_te, yl_te) = airfoil.surfaces(1.0);
+        assert!((yu_te - yl_te - params.te_thickness).abs() < 1e-5);
+        assert!((0.5 * (yu_te + yl_te) - params.y_te).abs() < 1e-5);
+        let (su_te, sl_te) = airfoil.slopes(1.0);
+        let upper_angle = su_te.atan();
+        let lower_angle = sl_te.atan();
+        assert!(
+            (lower_angle - upper_angle - params.te_wedge).abs() < 1e-4
+        );
+
+        let (upper, lower) = airfoil.coefficients();
+        assert!((upper[0] - (2.0 * 0.0155f64).sqrt()).abs() < 1e-6);
+        assert!((lower[0] + (2.0 * 0.006f64).sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let crossing = ParsecParams {
+            y_up: -0.07,
+            ..ParsecParams::default()
+        };
+        assert!(matches!(
+            crossing.validate(),
+            Err(ParsecError::SurfacesCross { .. })
+        ));
+
+        let no_radius = ParsecParams {
+            r_le_upper: 0.0,
+            ..ParsecParams::default()
+        };
+        assert_eq!(
+            no_radius.validate(),
+            Err(ParsecError::InvalidParameter("r_le"))
+        );
+        assert!(ParsecParams::default().validate().is_ok());
+    }
+
+    #[test]
+    fn default_section_resembles_naca0012() {
+        let airfoil = ParsecParams::default().build().unwrap();
+        let pts = build_parsec_geometry(&airfoil);
+        assert_eq!(pts.first(), pts.last());
+        let thickness = (1..100)
+            .map(|i| {
+                let (yu, yl) = airfoil.surfaces(i as f32 / 100.0);
+                yu - yl
+            })
+            .fold(0.0, f32::max);
+        assert!((thickness - 0.12).abs() < 0.005, "t={thickness}");
+    }
+}
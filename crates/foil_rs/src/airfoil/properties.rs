@@ -0,0 +1,215 @@
+//! Geometric properties of a section (the numbers XFoil's `GDES` prints).
+//!
+//! Everything is extracted numerically from the coordinate loop: the loop
+//! is splined, resampled densely and split into surfaces, from which the
+//! camber line and thickness distribution follow as the mean and the
+//! difference of the surface heights.
+
+use crate::math::Vec2;
+
+use super::shape::AirfoilShape;
+use super::spline::{LoopSpline, dedup_points, surface_run};
+use super::surfaces::Surfaces;
+
+/// Resampled points per input node when extracting the surfaces.
+const RESAMPLE_FACTOR: usize = 8;
+/// Chordwise stations scanned for the thickness and camber maxima.
+const SCAN_STATIONS: usize = 400;
+
+/// Geometric properties of a closed loop, in the loop's own units.
+///
+/// Chordwise locations are x coordinates, so they read as x/c for a loop
+/// normalized with `normalize_loop`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeometryProperties {
+    /// Maximum thickness (upper minus lower surface height).
+    pub max_thickness: f32,
+    pub max_thickness_x: f32,
+    /// Mean-line height with the largest magnitude (signed).
+    pub max_camber: f32,
+    pub max_camber_x: f32,
+    /// Radius of curvature at the leading edge.
+    pub le_radius: f32,
+    /// Gap between the two trailing-edge points.
+    pub te_thickness: f32,
+    /// Included angle between the surfaces at the trailing edge.
+    pub te_angle_deg: f32,
+    /// Enclosed area.
+    pub area: f32,
+    pub centroid: Vec2,
+    /// Length of the closed loop, including any TE closing segment.
+    pub perimeter: f32,
+}
+
+impl GeometryProperties {
+    /// Properties of any shape's panel loop.
+    pub fn of<A: AirfoilShape + ?Sized>(shape: &A) -> Option<Self> {
+        Self::from_loop(&shape.panel_points())
+    }
+
+    /// Properties of a loop in solver ordering (TE → lower → LE → upper).
+    ///
+    /// Returns `None` for loops with fewer than five distinct points or
+    /// zero area.
+    pub fn from_loop(points: &[Vec2]) -> Option<Self> {
+        // A sharp TE ends on the first point; the repeated point adds
+        // nothing to the polygon sums below.
+        let (open, _) = surface_run(points);
+        let pts = dedup_points(open, 1e-7);
+        if pts.len() < 5 {
+            return None;
+        }
+        let (area, centroid) = area_and_centroid(&pts)?;
+        let perimeter = (0..pts.len())
+            .map(|i| pts[i].distance(pts[(i + 1) % pts.len()]))
+            .sum();
+
+        let first = pts[0];
+        let last = pts[pts.len() - 1];
+        let spline = LoopSpline::new(&pts);
+        let length = spline.length();
+        let s_le = spline.farthest_from(0.5 * (first + last));
+        let le_radius = 1.0 / spline.curvature(s_le).abs().max(1e-9);
+
+        // Both tangents turned to point away from the trailing edge.
+        let lower_dir = spline.tangent(0.0).normalize_or_zero();
+        let upper_dir = -spline.tangent(length).normalize_or_zero();
+        let te_angle_deg = lower_dir
+            .dot(upper_dir)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+
+        let samples = RESAMPLE_FACTOR * pts.len();
+        let dense: Vec<Vec2> = (0..=samples)
+            .map(|i| spline.point(length * i as f32 / samples as f32))
+            .collect();
+        let surfaces = Surfaces::from_loop(&dense);
+        let x_le = spline.point(s_le).x;
+        let x_te = first.x.max(last.x);
+        let (max_thickness_x, max_thickness) =
+            scan_max(x_le, x_te, |x| surfaces.thickness(x));
+        let (max_camber_x, max_camber) =
+            scan_max(x_le, x_te, |x| surfaces.camber(x).abs());
+        let max_camber =
+            max_camber.copysign(surfaces.camber(max_camber_x));
+
+        Some(Self {
+            max_thickness,
+            max_thickness_x,
+            max_camber,
+            max_camber_x,
+            le_radius,
+            te_thickness: first.distance(last),
+            te_angle_deg,
+            area,
+            centroid,
+            perimeter,
+        })
+    }
+}
+
+/// `(x, f(x))` at the maximum of `f` over `[x0, x1]`: a cosine-spaced
+/// scan refined by a parabola through the best three stations.
+pub(super) fn scan_max(
+    x0: f32,
+    x1: f32,
+    f: impl Fn(f32) -> f32,
+) -> (f32, f32) {
+    let xs: Vec<f32> = (0..=SCAN_STATIONS)
+        .map(|i| {
+            let beta =
+                std::f32::consts::PI * i as f32 / SCAN_STATIONS as f32;
+            x0 + (x1 - x0) * 0.5 * (1.0 - beta.cos())
+        })
+        .collect();
+    let values: Vec<f32> = xs.iter().map(|&x| f(x)).collect();
+    let best = (0..values.len())
+        .max_by(|&a, &b| values[a].total_cmp(&values[b]))
+        .unwrap_or(0);
+    if best == 0 || best + 1 == values.len() {
+        return (xs[best], values[best]);
+    }
+
+    let (xa, xb, xc) = (xs[best - 1], xs[best], xs[best + 1]);
+    let (fa, fb, fc) =
+        (values[best - 1], values[best], values[best + 1]);
+    let num =
+        (xb - xa).powi(2) * (fb - fc) - (xb - xc).powi(2) * (fb - fa);
+    let den = (xb - xa) * (fb - fc) - (xb - xc) * (fb - fa);
+    if den.abs() < f32::EPSILON {
+        return (xb, fb);
+    }
+    let x = (xb - 0.5 * num / den).clamp(xa, xc);
+    (x, f(x).max(fb))
+}
+
+/// Absolute area and centroid of a closed polygon (implicitly closed).
+fn area_and_centroid(pts: &[Vec2]) -> Option<(f32, Vec2)> {
+    let n = pts.len();
+    let mut area = 0.0f64;
+    let mut cx = 0.0f64;
+    let mut cy = 0.0f64;
+    for i in 0..n {
+        let a = pts[i].as_dvec2();
+        let b = pts[(i + 1) % n].as_dvec2();
+        let cross = a.perp_dot(b);
+        area += cross;
+        cx += (a.x + b.x) * cross;
+        cy += (a.y + b.y) * cross;
+    }
+    area *= 0.5;
+    if area.abs() < 1e-12 {
+        return None;
+    }
+    let centroid = Vec2::new(
+        (cx / (6.0 * area)) as f32,
+        (cy / (6.0 * area)) as f32,
+    );
+    Some((area.abs() as f32, centroid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::NacaParams;
+
+    #[test]
+    fn naca_properties_match_closed_form() {
+        let params = NacaParams::from_naca4("2412").unwrap();
+        let props =
+            GeometryProperties::of(&params).expect("properties");
+
+        assert!((props.max_thickness - 0.12).abs() < 1e-3);
+        assert!((props.max_thickness_x - 0.30).abs() < 0.02);
+        assert!((props.max_camber - 0.02).abs() < 5e-4);
+        assert!((props.max_camber_x - 0.40).abs() < 0.02);
+        // NACA 4-digit LE radius: 1.1019 t².
+        let r_le = 1.1019 * 0.12 * 0.12;
+        assert!((props.le_radius - r_le).abs() < 0.1 * r_le);
+        // Area of the 4-digit thickness form: 0.685 t.
+        assert!((props.area - 0.685 * 0.12).abs() < 1e-3);
+        assert!(props.perimeter > 2.0 && props.perimeter < 2.1);
+        assert!(props.centroid.x > 0.38 && props.centroid.x < 0.45);
+    }
+
+    #[test]
+    fn te_angle_and_gap_of_symmetric_section() {
+        let props = GeometryProperties::of(
+            &NacaParams::from_naca4("0012").unwrap(),
+        )
+        .unwrap();
+        // Standard 4-digit TE: gap 2 y_t(1) = 10 t · 0.0021 and half
+        // angle atan(|y_t'(1)|) with
+        // y_t'(1) = 5 t (0.14845 - 0.126 - 0.7032 + 0.8529 - 0.4060).
+        let half = (5.0f32 * 0.12 * 0.23385).atan().to_degrees();
+        assert!((props.te_thickness - 0.00252).abs() < 1e-5);
+        assert!(
+            (props.te_angle_deg - 2.0 * half).abs() < 1.0,
+            "{}",
+            props.te_angle_deg
+        );
+        assert!(props.max_camber.abs() < 1e-4);
+        assert!(props.centroid.y.abs() < 1e-4);
+    }
+}
@@ -0,0 +1,306 @@
+//! Curvature-based repaneling of coordinate loops (XFoil `PANE`/`PPAR`).
+//!
+//! A parametric spline is fitted through the loop and the new nodes are
+//! placed by equidistributing a node density along its arc length. The
+//! density grows with the (smoothed) surface curvature and is floored near
+//! the trailing edge and inside optional refinement windows, each relative
+//! to the density at the leading edge.
+
+use crate::math::Vec2;
+
+use super::shape::AirfoilShape;
+use super::spline::{LoopSpline, dedup_points, surface_run};
+
+/// Paneling controls, named after their XFoil `PPAR` counterparts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PanelingParams {
+    /// Number of surface nodes (`N`).
+    pub num_nodes: usize,
+    /// Curvature attraction (`CVPAR`); 0 gives uniform arc-length spacing.
+    pub curvature_weight: f32,
+    /// TE / LE node density ratio (`CTERAT`).
+    pub te_le_ratio: f32,
+    /// Refinement-window / LE node density ratio (`CTRRAT`).
+    pub refine_ratio: f32,
+    /// Upper-surface refinement window in x/c (`XSREF1`, `XSREF2`).
+    pub upper_refinement: Option<(f32, f32)>,
+    /// Lower-surface refinement window in x/c (`XPREF1`, `XPREF2`).
+    pub lower_refinement: Option<(f32, f32)>,
+}
+
+impl Default for PanelingParams {
+    fn default() -> Self {
+        Self {
+            num_nodes: 160,
+            curvature_weight: 1.0,
+            te_le_ratio: 0.15,
+            refine_ratio: 0.2,
+            upper_refinement: None,
+            lower_refinement: None,
+        }
+    }
+}
+
+/// Density samples per output node when equidistributing.
+const SAMPLES_PER_NODE: usize = 16;
+/// Width of the TE density bump, as a fraction of the perimeter.
+const TE_BUMP_WIDTH: f32 = 0.02;
+
+/// Redistribute the nodes of any closed loop.
+///
+/// Keeps the solver ordering of the input (TE lower → LE → TE upper) and
+/// closes the result like `build_naca_body_geometry_sharp_te`: the last
+/// point repeats the first. Returns the input unchanged if it has too few
+/// distinct points to spline.
+pub fn repanel(points: &[Vec2], params: &PanelingParams) -> Vec<Vec2> {
+    let scale = points
+        .iter()
+        .fold(0.0f32, |acc, p| acc.max(p.x.abs()).max(p.y.abs()))
+        .max(1.0);
+    let tol = 1e-7 * scale;
+    // Lower TE → upper TE; the two coincide for a sharp trailing edge.
+    let (open, closed) = surface_run(points);
+    let contour = dedup_points(open, tol);
+    if contour.len() < 5 || params.num_nodes < 5 {
+        return points.to_vec();
+    }
+    let spline = LoopSpline::new(&contour);
+    let length = spline.length();
+    let first = contour[0];
+
+    let te = 0.5 * (first + contour[contour.len() - 1]);
+    let s_le = spline.farthest_from(te);
+    let le = spline.point(s_le);
+
+    let samples = SAMPLES_PER_NODE * params.num_nodes;
+    let ds = length / (samples - 1) as f32;
+    let s_at = |i: usize| i as f32 * ds;
+
+    let density =
+        node_density(&spline, params, samples, ds, s_le, le, te);
+
+    // Cumulative density (trapezoid), then invert at equal increments.
+    let mut cumulative = Vec::with_capacity(samples);
+    cumulative.push(0.0f32);
+    for i in 1..samples {
+        let prev = cumulative[i - 1];
+        cumulative
+            .push(prev + 0.5 * (density[i - 1] + density[i]) * ds);
+    }
+    let total = cumulative[samples - 1];
+
+    let n = params.num_nodes;
+    let mut out = Vec::with_capacity(n + 1);
+    out.push(first);
+    let mut j = 0;
+    for k in 1..n - 1 {
+        let target = total * k as f32 / (n - 1) as f32;
+        while j + 2 < samples && cumulative[j + 1] < target {
+            j += 1;
+        }
+        let span =
+            (cumulative[j + 1] - cumulative[j]).max(f32::EPSILON);
+        let t = ((target - cumulative[j]) / span).clamp(0.0, 1.0);
+        out.push(spline.point(s_at(j) + t * ds));
+    }
+    out.push(contour[contour.len() - 1]);
+    if closed {
+        // Snap the coincident TE nodes together.
+        out[n - 1] = first;
+    } else {
+        out.push(first);
+    }
+    out
+}
+
+/// Node density at `samples` uniform arc-length stations.
+fn node_density(
+    spline: &LoopSpline,
+    params: &PanelingParams,
+    samples: usize,
+    ds: f32,
+    s_le: f32,
+    le: Vec2,
+    te: Vec2,
+) -> Vec<f32> {
+    let length = spline.length();
+    let mut curvature: Vec<f32> = (0..samples)
+        .map(|i| spline.curvature(i as f32 * ds).abs())
+        .collect();
+    // A few 1-2-1 passes take out spline wiggles from sparse inputs.
+    for _ in 0..8 {
+        let prev = curvature.clone();
+        for i in 1..samples - 1 {
+            curvature[i] =
+                0.25 * (prev[i - 1] + 2.0 * prev[i] + prev[i + 1]);
+        }
+    }
+    let mean = (curvature.iter().sum::<f32>() / samples as f32)
+        .max(f32::EPSILON);
+
+    let mut density: Vec<f32> = curvature
+        .iter()
+        .map(|k| 1.0 + params.curvature_weight.max(0.0) * k / mean)
+        .collect();
+    let le_index = ((s_le / ds).round() as usize).min(samples - 1);
+    let le_density = density[le_index];
+
+    let chord = (te - le).length().max(f32::EPSILON);
+    let axis = (te - le) / chord;
+    let width = TE_BUMP_WIDTH * length;
+    for (i, d) in density.iter_mut().enumerate() {
+        let s = i as f32 * ds;
+        let to_te = s.min(length - s) / width;
+        let te_floor =
+            params.te_le_ratio * le_density * (-to_te * to_te).exp();
+
+        let x_c = (spline.point(s) - le).dot(axis) / chord;
+        let window = if s < s_le {
+            params.lower_refinement
+        } else {
+            params.upper_refinement
+        };
+        let window_floor = match window {
+            Some((x0, x1))
+                if x_c >= x0.min(x1) && x_c <= x0.max(x1) =>
+            {
+                params.refine_ratio * le_density
+            }
+            _ => 0.0,
+        };
+        *d = d.max(te_floor).max(window_floor);
+    }
+    density
+}
+
+/// Any shape, repaneled on the fly before it reaches the solvers.
+///
+/// Analytic surfaces and the drawing loop pass through untouched, so only
+/// the panel distribution changes.
+#[derive(Clone, Debug)]
+pub struct Repaneled<A> {
+    pub shape: A,
+    pub params: PanelingParams,
+}
+
+impl<A> Repaneled<A> {
+    pub fn new(shape: A, params: PanelingParams) -> Self {
+        Self { shape, params }
+    }
+}
+
+impl<A: AirfoilShape> AirfoilShape for Repaneled<A> {
+    fn name(&self) -> String {
+        self.shape.name()
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        repanel(&self.shape.panel_points(), &self.params)
+    }
+
+    fn body_points(&self) -> Vec<Vec2> {
+        self.shape.body_points()
+    }
+
+    fn analytic_surfaces(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        self.shape.analytic_surfaces(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::naca_loop_with_points;
+
+    fn distance_to_polyline(p: Vec2, poly: &[Vec2]) -> f32 {
+        poly.windows(2)
+            .map(|w| {
+                let d = w[1] - w[0];
+                let t = ((p - w[0]).dot(d)
+                    / d.length_squared().max(1e-12))
+                .clamp(0.0, 1.0);
+                p.distance(w[0] + t * d)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Spacing between consecutive nodes nearest to `target`.
+    fn spacing_near(pts: &[Vec2], target: Vec2) -> f32 {
+        let i = (1..pts.len() - 1)
+            .min_by(|&a, &b| {
+                pts[a]
+                    .distance(target)
+                    .total_cmp(&pts[b].distance(target))
+            })
+            .unwrap();
+        pts[i].distance(pts[i + 1])
+    }
+
+    #[test]
+    fn repanel_keeps_ordering_and_shape() {
+        let dense = naca_loop_with_points("2412", 400);
+        let params = PanelingParams {
+            num_nodes: 120,
+            ..Default::default()
+        };
+        let pts = repanel(&dense, &params);
+
+        assert_eq!(pts.len(), 121);
+        assert_eq!(pts[0], *pts.last().unwrap());
+        assert_eq!(pts[0], dense[0]);
+        // Lower surface first.
+        assert!(pts[10].y < 0.0 && pts[110].y > 0.0);
+        for p in &pts {
+            assert!(distance_to_polyline(*p, &dense) < 2e-4, "{p}");
+        }
+    }
+
+    #[test]
+    fn curvature_clusters_nodes_at_le_and_te() {
+        let dense = naca_loop_with_points("0012", 400);
+        let pts = repanel(&dense, &PanelingParams::default());
+        let le = spacing_near(&pts, Vec2::ZERO);
+        let mid = spacing_near(&pts, Vec2::new(0.5, 0.05));
+        let te = spacing_near(&pts, Vec2::new(0.99, 0.0));
+        assert!(le < 0.2 * mid, "le {le} vs mid {mid}");
+        assert!(te < mid, "te {te} vs mid {mid}");
+
+        let uniform = repanel(
+            &dense,
+            &PanelingParams {
+                curvature_weight: 0.0,
+                te_le_ratio: 0.0,
+                ..Default::default()
+            },
+        );
+        // Skip the closing segment across the blunt TE.
+        let surface = &uniform[..uniform.len() - 1];
+        let gaps: Vec<f32> =
+            surface.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let (lo, hi) =
+            gaps.iter().fold((f32::MAX, 0.0f32), |(lo, hi), &g| {
+                (lo.min(g), hi.max(g))
+            });
+        assert!(hi / lo < 1.05, "uniform spacing spread {lo}..{hi}");
+    }
+
+    #[test]
+    fn refinement_window_adds_nodes_on_one_side() {
+        let dense = naca_loop_with_points("0012", 400);
+        let base = PanelingParams::default();
+        let refined = PanelingParams {
+            upper_refinement: Some((0.4, 0.6)),
+            ..base.clone()
+        };
+        let count = |pts: &[Vec2], upper: bool| {
+            pts.iter()
+                .filter(|p| (p.y > 0.0) == upper)
+                .filter(|p| (0.4..=0.6).contains(&p.x))
+                .count()
+        };
+        let before = repanel(&dense, &base);
+        let after = repanel(&dense, &refined);
+        assert!(count(&after, true) > 2 * count(&before, true));
+        assert!(count(&after, false) <= count(&before, false));
+    }
+}
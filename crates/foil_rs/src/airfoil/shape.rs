@@ -0,0 +1,77 @@
+use crate::math::Vec2;
+
+use super::dat::DatAirfoil;
+
+/// A section that the panel, boundary-layer and polar code can analyze.
+///
+/// Implementors only need to provide a coordinate loop; shapes with
+/// closed-form surfaces can additionally expose them so Cp sampling uses
+/// exact surface points instead of interpolating the loop.
+pub trait AirfoilShape {
+    /// Display name (e.g. `"NACA 2412"`).
+    fn name(&self) -> String;
+
+    /// Closed loop used to build solver panels.
+    ///
+    /// Ordering matches `build_naca_body_geometry_sharp_te`: starts at the
+    /// trailing edge, walks the lower surface to the leading edge and
+    /// returns along the upper surface. The last point repeats the first.
+    fn panel_points(&self) -> Vec<Vec2>;
+
+    /// Loop used for drawing. Defaults to `panel_points`.
+    fn body_points(&self) -> Vec<Vec2> {
+        self.panel_points()
+    }
+
+    /// Exact `(upper, lower)` surface points at chord station `x` (0..1),
+    /// for shapes defined by formulas rather than coordinates.
+    fn analytic_surfaces(&self, _x: f32) -> Option<(Vec2, Vec2)> {
+        None
+    }
+}
+
+/// An airfoil defined by a coordinate loop (imported, edited or generated).
+#[derive(Clone, Debug, Default)]
+pub struct Airfoil {
+    pub name: String,
+    /// Coordinates in solver ordering (see `AirfoilShape::panel_points`).
+    /// The loop is closed on demand if the last point differs from the
+    /// first.
+    pub points: Vec<Vec2>,
+}
+
+impl Airfoil {
+    pub fn new(name: impl Into<String>, points: Vec<Vec2>) -> Self {
+        Self {
+            name: name.into(),
+            points,
+        }
+    }
+
+    /// Snapshot any shape's panel loop into a plain coordinate airfoil.
+    pub fn from_shape<A: AirfoilShape + ?Sized>(shape: &A) -> Self {
+        Self::new(shape.name(), shape.panel_points())
+    }
+}
+
+impl From<DatAirfoil> for Airfoil {
+    fn from(dat: DatAirfoil) -> Self {
+        Self::new(dat.name, dat.points)
+    }
+}
+
+impl AirfoilShape for Airfoil {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn panel_points(&self) -> Vec<Vec2> {
+        let mut pts = self.points.clone();
+        if let (Some(&first), Some(&last)) = (pts.first(), pts.last())
+            && first.distance(last) > 1e-7
+        {
+            pts.push(first);
+        }
+        pts
+    }
+}
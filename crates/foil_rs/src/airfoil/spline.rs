@@ -0,0 +1,248 @@
+//! Parametric cubic splines through coordinate loops.
+
+use crate::math::Vec2;
+
+/// Natural cubic spline `f(t)` through `(t_i, f_i)` with increasing `t`.
+#[derive(Clone, Debug)]
+struct CubicSpline {
+    t: Vec<f64>,
+    f: Vec<f64>,
+    /// Second derivatives at the knots.
+    m: Vec<f64>,
+}
+
+impl CubicSpline {
+    fn new(t: Vec<f64>, f: Vec<f64>) -> Self {
+        let n = t.len();
+        let mut m = vec![0.0; n];
+        if n > 2 {
+            // Tridiagonal system for the interior second derivatives.
+            let mut diag = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+            let mut upper = vec![0.0; n];
+            for i in 1..n - 1 {
+                let h0 = t[i] - t[i - 1];
+                let h1 = t[i + 1] - t[i];
+                diag[i] = 2.0 * (h0 + h1);
+                upper[i] = h1;
+                rhs[i] = 6.0
+                    * ((f[i + 1] - f[i]) / h1 - (f[i] - f[i - 1]) / h0);
+            }
+            // Thomas algorithm on rows 1..n-1 (m[0] = m[n-1] = 0).
+            for i in 2..n - 1 {
+                let w = (t[i] - t[i - 1]) / diag[i - 1];
+                diag[i] -= w * upper[i - 1];
+                rhs[i] -= w * rhs[i - 1];
+            }
+            for i in (1..n - 1).rev() {
+                m[i] = (rhs[i] - upper[i] * m[i + 1]) / diag[i];
+            }
+        }
+        Self { t, f, m }
+    }
+
+    fn segment(&self, t: f64) -> usize {
+        let n = self.t.len();
+        self.t.partition_point(|&v| v <= t).clamp(1, n - 1) - 1
+    }
+
+    /// `(f, f', f'')` at `t` (clamped to the knot range).
+    fn eval(&self, t: f64) -> (f64, f64, f64) {
+        if self.t.len() < 2 {
+            return (self.f.first().copied().unwrap_or(0.0), 0.0, 0.0);
+        }
+        let i = self.segment(t);
+        let h = self.t[i + 1] - self.t[i];
+        let t = t.clamp(self.t[0], self.t[self.t.len() - 1]);
+        let a = (self.t[i + 1] - t) / h;
+        let b = (t - self.t[i]) / h;
+        let (m0, m1) = (self.m[i], self.m[i + 1]);
+        let (f0, f1) = (self.f[i], self.f[i + 1]);
+
+        let value = a * f0
+            + b * f1
+            + ((a * a * a - a) * m0 + (b * b * b - b) * m1) * h * h
+                / 6.0;
+        let slope = (f1 - f0) / h
+            + ((1.0 - 3.0 * a * a) * m0 + (3.0 * b * b - 1.0) * m1) * h
+                / 6.0;
+        let second = a * m0 + b * m1;
+        (value, slope, second)
+    }
+}
+
+/// Arc-length parameterized spline `(x(s), y(s))` through a point list.
+#[derive(Clone, Debug)]
+pub(crate) struct LoopSpline {
+    s: Vec<f64>,
+    x: CubicSpline,
+    y: CubicSpline,
+}
+
+impl LoopSpline {
+    /// Spline through `points`, parameterized by cumulative chord length.
+    /// Consecutive duplicates must already be removed.
+    pub(crate) fn new(points: &[Vec2]) -> Self {
+        let mut s = Vec::with_capacity(points.len());
+        let mut acc = 0.0f64;
+        for (i, p) in points.iter().enumerate() {
+            if i > 0 {
+                acc += p.distance(points[i - 1]) as f64;
+            }
+            s.push(acc);
+        }
+        let xs = points.iter().map(|p| p.x as f64).collect();
+        let ys = points.iter().map(|p| p.y as f64).collect();
+        Self {
+            x: CubicSpline::new(s.clone(), xs),
+            y: CubicSpline::new(s.clone(), ys),
+            s,
+        }
+    }
+
+    /// Parameter at each of the input points.
+    pub(crate) fn knots(&self) -> impl Iterator<Item = f32> + '_ {
+        self.s.iter().map(|&s| s as f32)
+    }
+
+    /// Total parameter length.
+    pub(crate) fn length(&self) -> f32 {
+        self.s.last().copied().unwrap_or(0.0) as f32
+    }
+
+    pub(crate) fn point(&self, s: f32) -> Vec2 {
+        let s = s as f64;
+        Vec2::new(self.x.eval(s).0 as f32, self.y.eval(s).0 as f32)
+    }
+
+    /// Derivative `(x'(s), y'(s))`, roughly unit length.
+    pub(crate) fn tangent(&self, s: f32) -> Vec2 {
+        let s = s as f64;
+        Vec2::new(self.x.eval(s).1 as f32, self.y.eval(s).1 as f32)
+    }
+
+    /// Signed curvature at `s` (positive when turning left).
+    pub(crate) fn curvature(&self, s: f32) -> f32 {
+        let s = s as f64;
+        let (_, xs, xss) = self.x.eval(s);
+        let (_, ys, yss) = self.y.eval(s);
+        let speed = (xs * xs + ys * ys).sqrt().max(1e-12);
+        ((xs * yss - ys * xss) / (speed * speed * speed)) as f32
+    }
+
+    /// Parameter of the spline point farthest from `te` (XFoil's leading
+    /// edge definition): where the surface tangent is normal to the line
+    /// from `te`, bracketed around the farthest knot.
+    pub(crate) fn farthest_from(&self, te: Vec2) -> f32 {
+        let (tx, ty) = (te.x as f64, te.y as f64);
+        let dist_sq = |s: f64| {
+            let (x, y) = (self.x.eval(s).0 - tx, self.y.eval(s).0 - ty);
+            x * x + y * y
+        };
+        // Half the derivative of the squared distance.
+        let slope = |s: f64| {
+            let (x, dx, _) = self.x.eval(s);
+            let (y, dy, _) = self.y.eval(s);
+            (x - tx) * dx + (y - ty) * dy
+        };
+        let Some(best) = (0..self.s.len()).max_by(|&a, &b| {
+            dist_sq(self.s[a]).total_cmp(&dist_sq(self.s[b]))
+        }) else {
+            return 0.0;
+        };
+        let mut lo = self.s[best.saturating_sub(1)];
+        let mut hi = self.s[(best + 1).min(self.s.len() - 1)];
+        if slope(lo) < 0.0 || slope(hi) > 0.0 {
+            return self.s[best] as f32;
+        }
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if slope(mid) > 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (0.5 * (lo + hi)) as f32
+    }
+}
+
+/// The surface run of a loop in solver ordering, from the lower to the
+/// upper trailing-edge point, and whether the trailing edge is sharp.
+///
+/// A closing point that repeats the first is dropped when it closes an
+/// open trailing edge, i.e. when the last segment runs across the chord
+/// line rather than along it. A sharp TE keeps its final point, so the run
+/// starts and ends on the same point.
+pub(crate) fn surface_run(points: &[Vec2]) -> (&[Vec2], bool) {
+    let n = points.len();
+    if n < 3 || points[0].distance(points[n - 1]) > 1e-7 {
+        return (points, false);
+    }
+    let (first, prev, last) = (points[0], points[n - 2], points[n - 1]);
+    if prev.distance(first) <= 1e-7 {
+        // Sharp TE written twice before the closing point.
+        return (&points[..n - 1], true);
+    }
+    let le = points
+        .iter()
+        .copied()
+        .min_by(|a, b| a.x.total_cmp(&b.x))
+        .unwrap_or(first);
+    let axis = (first - le).normalize_or_zero();
+    let d = last - prev;
+    if d.dot(axis) < d.perp_dot(axis).abs() {
+        (&points[..n - 1], false)
+    } else {
+        (points, true)
+    }
+}
+
+/// Close a surface run the way the NACA builders do: snap a sharp TE onto
+/// the first point, otherwise append the first point.
+pub(crate) fn close_run(run: &mut Vec<Vec2>) {
+    let Some(&first) = run.first() else {
+        return;
+    };
+    let last = run.len() - 1;
+    if run[last].distance(first) <= 1e-6 {
+        run[last] = first;
+    } else {
+        run.push(first);
+    }
+}
+
+/// Copy of `points` without consecutive points closer than `tol`.
+pub(crate) fn dedup_points(points: &[Vec2], tol: f32) -> Vec<Vec2> {
+    let mut out: Vec<Vec2> = Vec::with_capacity(points.len());
+    for &p in points {
+        if out.last().is_none_or(|q| q.distance(p) > tol) {
+            out.push(p);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn circle_spline_has_unit_curvature() {
+        let pts: Vec<Vec2> = (0..=64)
+            .map(|i| {
+                let t = 2.0 * PI * i as f32 / 64.0;
+                Vec2::new(t.cos(), t.sin())
+            })
+            .collect();
+        let spline = LoopSpline::new(&pts);
+        let mid = 0.5 * spline.length();
+        assert!((spline.curvature(mid) - 1.0).abs() < 1e-2);
+        assert!(
+            (spline.point(mid) - Vec2::new(-1.0, 0.0)).length() < 1e-3
+        );
+        let far = spline.farthest_from(Vec2::new(1.0, 0.0));
+        assert!((far - mid).abs() < 1e-3);
+    }
+}
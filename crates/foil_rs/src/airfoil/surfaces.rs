@@ -0,0 +1,106 @@
+use crate::math::Vec2;
+
+/// Upper and lower surfaces of a coordinate loop, both ordered LE → TE.
+///
+/// Used to evaluate surface heights, camber and thickness at arbitrary x/c
+/// for shapes that only exist as a list of points.
+#[derive(Clone, Debug, Default)]
+pub struct Surfaces {
+    pub upper: Vec<Vec2>,
+    pub lower: Vec<Vec2>,
+}
+
+impl Surfaces {
+    /// Split a loop at its leading edge (minimum x).
+    ///
+    /// Accepts the solver ordering (TE → lower → LE → upper → TE) as well as
+    /// the reversed Selig ordering; the higher half is taken as upper.
+    pub fn from_loop(points: &[Vec2]) -> Self {
+        // Drop the closing point so an open trailing edge does not end
+        // one surface on the other surface's TE point.
+        let points = match points {
+            [first, .., last] if first.distance(*last) < 1e-7 => {
+                &points[..points.len() - 1]
+            }
+            _ => points,
+        };
+        if points.len() < 3 {
+            return Self::default();
+        }
+        let le = points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+            .map(|(idx, _)| idx)
+            .unwrap_or(0);
+
+        let mut first: Vec<Vec2> = points[..=le].to_vec();
+        first.reverse();
+        let second: Vec<Vec2> = points[le..].to_vec();
+
+        let mean_y = |s: &[Vec2]| {
+            s.iter().map(|p| p.y).sum::<f32>() / s.len().max(1) as f32
+        };
+        if mean_y(&first) > mean_y(&second) {
+            Self {
+                upper: first,
+                lower: second,
+            }
+        } else {
+            Self {
+                upper: second,
+                lower: first,
+            }
+        }
+    }
+
+    /// Upper-surface height at `x` (clamped to the surface extent).
+    pub fn upper_y(&self, x: f32) -> f32 {
+        interpolate_y(&self.upper, x)
+    }
+
+    /// Lower-surface height at `x` (clamped to the surface extent).
+    pub fn lower_y(&self, x: f32) -> f32 {
+        interpolate_y(&self.lower, x)
+    }
+
+    /// `(upper, lower)` surface points at `x`.
+    pub fn at(&self, x: f32) -> (Vec2, Vec2) {
+        (Vec2::new(x, self.upper_y(x)), Vec2::new(x, self.lower_y(x)))
+    }
+
+    /// Mean line height at `x`.
+    pub fn camber(&self, x: f32) -> f32 {
+        0.5 * (self.upper_y(x) + self.lower_y(x))
+    }
+
+    /// Full thickness (upper minus lower) at `x`.
+    pub fn thickness(&self, x: f32) -> f32 {
+        self.upper_y(x) - self.lower_y(x)
+    }
+}
+
+/// Linear interpolation of `y(x)` along a polyline ordered by increasing x.
+fn interpolate_y(pts: &[Vec2], x: f32) -> f32 {
+    match pts {
+        [] => 0.0,
+        [only] => only.y,
+        _ => {
+            let idx = pts.partition_point(|p| p.x < x);
+            if idx == 0 {
+                return pts[0].y;
+            }
+            if idx >= pts.len() {
+                return pts[pts.len() - 1].y;
+            }
+            let a = pts[idx - 1];
+            let b = pts[idx];
+            let dx = b.x - a.x;
+            if dx.abs() < 1e-9 {
+                return 0.5 * (a.y + b.y);
+            }
+            let t = ((x - a.x) / dx).clamp(0.0, 1.0);
+            a.y + t * (b.y - a.y)
+        }
+    }
+}
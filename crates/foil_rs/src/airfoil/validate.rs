@@ -0,0 +1,283 @@
+//! Sanity checks for coordinate loops before they reach the solvers.
+//!
+//! Problems that make the panel system meaningless (open or crossing
+//! loops, coincident nodes) are errors; problems the solver survives but
+//! that usually hurt accuracy (reversed ordering, tiny or badly graded
+//! panels, cusps) are warnings.
+
+use std::fmt;
+
+use crate::math::{Vec2, signed_area};
+
+/// Fewest points (including the closing point) a loop can have.
+pub const MIN_LOOP_POINTS: usize = 5;
+/// Segments shorter than this fraction of the median are near-duplicates.
+const NEAR_DUPLICATE_FRACTION: f32 = 1e-2;
+/// Largest acceptable length ratio between neighbouring panels.
+const MAX_ADJACENT_RATIO: f32 = 8.0;
+/// Turning angle at a node above which it is reported as a cusp.
+const CUSP_ANGLE_DEG: f32 = 90.0;
+
+/// A loop the panel solver cannot use.
+///
+/// Indices refer to the loop as passed in; segment `i` joins points `i`
+/// and `i + 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeometryError {
+    TooFewPoints {
+        found: usize,
+    },
+    NonFinite {
+        index: usize,
+    },
+    /// The last point does not repeat the first.
+    OpenLoop {
+        gap: f32,
+    },
+    /// Point `index` coincides with the point before it.
+    DuplicateNode {
+        index: usize,
+    },
+    /// Segments `first` and `second` cross.
+    SelfIntersection {
+        first: usize,
+        second: usize,
+    },
+    /// The loop encloses no area.
+    ZeroArea,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewPoints { found } => write!(
+                f,
+                "expected at least {MIN_LOOP_POINTS} points, found {found}"
+            ),
+            Self::NonFinite { index } => {
+                write!(f, "point {index} is not finite")
+            }
+            Self::OpenLoop { gap } => write!(
+                f,
+                "loop is not closed (last point is {gap:.3e} from the first)"
+            ),
+            Self::DuplicateNode { index } => {
+                write!(f, "point {index} duplicates the previous point")
+            }
+            Self::SelfIntersection { first, second } => {
+                write!(f, "segments {first} and {second} intersect")
+            }
+            Self::ZeroArea => write!(f, "loop encloses no area"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
+/// A loop the solver accepts but that is likely to degrade results.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeometryWarning {
+    /// The loop runs TE → upper → LE → lower. The panel solvers run it
+    /// backwards; `normalize_loop` fixes it for good.
+    ReversedOrdering,
+    /// Segment `index` is much shorter than the typical panel.
+    NearDuplicate { index: usize, length: f32 },
+    /// Segment `index` is `ratio` times longer or shorter than the one
+    /// before it.
+    PanelLengthRatio { index: usize, ratio: f32 },
+    /// The surface folds back on itself at point `index`.
+    Cusp { index: usize, angle_deg: f32 },
+}
+
+impl fmt::Display for GeometryWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReversedOrdering => {
+                write!(f, "loop runs over the upper surface first")
+            }
+            Self::NearDuplicate { index, length } => write!(
+                f,
+                "segment {index} is nearly degenerate ({length:.3e} long)"
+            ),
+            Self::PanelLengthRatio { index, ratio } => write!(
+                f,
+                "segment {index} differs from its neighbour by {ratio:.1}x"
+            ),
+            Self::Cusp { index, angle_deg } => write!(
+                f,
+                "surface turns by {angle_deg:.0}° at point {index}"
+            ),
+        }
+    }
+}
+
+/// Check a closed loop in solver ordering.
+///
+/// Returns the warnings when the loop is usable, otherwise the first
+/// error found.
+pub fn validate_loop(
+    points: &[Vec2],
+) -> Result<Vec<GeometryWarning>, GeometryError> {
+    let n = points.len();
+    if n < MIN_LOOP_POINTS {
+        return Err(GeometryError::TooFewPoints { found: n });
+    }
+    if let Some(index) = points.iter().position(|p| !p.is_finite()) {
+        return Err(GeometryError::NonFinite { index });
+    }
+
+    let extent = points
+        .iter()
+        .fold(0.0f32, |acc, p| acc.max(p.x.abs()).max(p.y.abs()))
+        .max(f32::MIN_POSITIVE);
+    let tol = 1e-6 * extent;
+    let gap = points[0].distance(points[n - 1]);
+    if gap > tol {
+        return Err(GeometryError::OpenLoop { gap });
+    }
+
+    let lengths: Vec<f32> =
+        points.windows(2).map(|w| w[0].distance(w[1])).collect();
+    if let Some(seg) = lengths.iter().position(|&len| len <= tol) {
+        return Err(GeometryError::DuplicateNode { index: seg + 1 });
+    }
+    if let Some((first, second)) = find_crossing(points) {
+        return Err(GeometryError::SelfIntersection { first, second });
+    }
+
+    let area = signed_area(points);
+    if area.abs() <= tol * tol {
+        return Err(GeometryError::ZeroArea);
+    }
+
+    let mut warnings = Vec::new();
+    // TE → lower → LE → upper runs clockwise (negative area).
+    if area > 0.0 {
+        warnings.push(GeometryWarning::ReversedOrdering);
+    }
+
+    // The last segment closes the trailing edge and is naturally short.
+    let surface = &lengths[..lengths.len() - 1];
+    let mut sorted = surface.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let median = sorted[sorted.len() / 2];
+    for (index, &length) in surface.iter().enumerate() {
+        if length < NEAR_DUPLICATE_FRACTION * median {
+            warnings
+                .push(GeometryWarning::NearDuplicate { index, length });
+        }
+    }
+    for index in 1..surface.len() {
+        let (a, b) = (surface[index - 1], surface[index]);
+        let ratio = a.max(b) / a.min(b);
+        if ratio > MAX_ADJACENT_RATIO {
+            warnings.push(GeometryWarning::PanelLengthRatio {
+                index,
+                ratio,
+            });
+        }
+    }
+    for index in 1..n - 1 {
+        let d0 = points[index] - points[index - 1];
+        let d1 = points[index + 1] - points[index];
+        let angle_deg = d0.angle_to(d1).abs().to_degrees();
+        if angle_deg > CUSP_ANGLE_DEG {
+            warnings.push(GeometryWarning::Cusp { index, angle_deg });
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// First pair of non-adjacent segments that cross, if any.
+fn find_crossing(points: &[Vec2]) -> Option<(usize, usize)> {
+    let segments = points.len() - 1;
+    for i in 0..segments {
+        let (a0, a1) = (points[i], points[i + 1]);
+        let (min_a, max_a) = (a0.min(a1), a0.max(a1));
+        for j in i + 2..segments {
+            // The first and last segments share the closing point.
+            if i == 0 && j == segments - 1 {
+                continue;
+            }
+            let (b0, b1) = (points[j], points[j + 1]);
+            if b0.max(b1).cmplt(min_a).any()
+                || b0.min(b1).cmpgt(max_a).any()
+            {
+                continue;
+            }
+            if segments_cross(a0, a1, b0, b1) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+fn segments_cross(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> bool {
+    let side = |p: Vec2, q0: Vec2, q1: Vec2| (q1 - q0).perp_dot(p - q0);
+    let (d1, d2) = (side(b0, a0, a1), side(b1, a0, a1));
+    let (d3, d4) = (side(a0, b0, b1), side(a1, b0, b1));
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airfoil::naca_loop;
+
+    #[test]
+    fn generated_loop_is_clean() {
+        let warnings =
+            validate_loop(&naca_loop("0012")).expect("valid loop");
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn detects_structural_errors() {
+        let pts = naca_loop("0012");
+
+        let open = &pts[..pts.len() - 1];
+        assert!(matches!(
+            validate_loop(open),
+            Err(GeometryError::OpenLoop { .. })
+        ));
+
+        let mut dup = pts.clone();
+        dup.insert(20, dup[20]);
+        assert_eq!(
+            validate_loop(&dup),
+            Err(GeometryError::DuplicateNode { index: 21 })
+        );
+
+        // Pull a lower-surface point through the upper surface.
+        let mut crossed = pts.clone();
+        let mid = crossed.len() / 4;
+        crossed[mid].y = 0.2;
+        assert!(matches!(
+            validate_loop(&crossed),
+            Err(GeometryError::SelfIntersection { .. })
+        ));
+    }
+
+    #[test]
+    fn reports_ordering_and_spacing_warnings() {
+        let mut reversed = naca_loop("0012");
+        reversed.reverse();
+        let warnings = validate_loop(&reversed).unwrap();
+        assert!(warnings.contains(&GeometryWarning::ReversedOrdering));
+
+        let mut squeezed = naca_loop("0012");
+        let mid = squeezed.len() / 4;
+        squeezed[mid] = squeezed[mid - 1].lerp(squeezed[mid], 1e-3);
+        let warnings = validate_loop(&squeezed).unwrap();
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            GeometryWarning::NearDuplicate { .. }
+        )));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            GeometryWarning::PanelLengthRatio { .. }
+        )));
+    }
+}
@@ -1,9 +1,19 @@
 //! FoilRs core library (no Bevy dependency).
 //!
 //! This crate provides:
-//! - NACA 4-digit airfoil geometry generation (`airfoil`)
-//! - A lightweight vortex panel solver + Cp sampling (`solvers::panel`)
+//! - NACA 4-digit (standard and modified), 5-digit and 6-series airfoil
+//!   generation, CST/PARSEC parameterizations, Selig/Lednicer `.dat`
+//!   import/export, curvature-based repaneling, plain flaps and
+//!   TE gap / LE radius / thickness-camber edits and two-section
+//!   blending (`airfoil`)
+//! - Joukowski / Kármán–Trefftz sections with their exact potential flow,
+//!   and error norms of the panel solvers against it
+//!   (`airfoil::karman_trefftz`, `solvers::validation`)
+//! - Constant-source (Hess–Smith) and linear-vorticity (XFoil-style)
+//!   panel solvers + Cp sampling (`solvers::panel`)
 //! - Polar sweeps and CSV-friendly result structures (`solvers::polar`)
+//! - Thin-airfoil theory for any mean line, behind the approximate mode
+//!   (`solvers::thin_airfoil`)
 //! - A small boundary-layer estimate for profile drag (`solvers::boundary_layer`)
 //!
 //! For an interactive UI front-end, see the `foil_rs_bevy` crate.
@@ -1 +1,182 @@
-pub use glam::Vec2;
+pub use glam::{DVec2, Vec2};
+
+/// Signed area of a polygon (shoelace); the loop is closed implicitly,
+/// so a repeated first point adds nothing. Positive when
+/// counter-clockwise.
+pub fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    0.5 * (0..n)
+        .map(|i| points[i].perp_dot(points[(i + 1) % n]))
+        .sum::<f32>()
+}
+
+/// Pivot that ended a `DenseLu` factorization: no candidate in `column`
+/// was larger than the tolerance; `pivot` is the largest one left.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SingularPivot {
+    pub column: usize,
+    pub pivot: f64,
+}
+
+/// LU factorization with partial pivoting of a dense row-major `n × n`
+/// matrix, reusable for any number of right-hand sides.
+#[derive(Clone, Debug)]
+pub struct DenseLu {
+    lu: Vec<f64>,
+    pivots: Vec<usize>,
+}
+
+impl DenseLu {
+    /// Factor `a`. A pivot at or below `1e-13` times the largest entry
+    /// counts as singular.
+    pub fn new(
+        mut lu: Vec<f64>,
+        n: usize,
+    ) -> Result<Self, SingularPivot> {
+        debug_assert_eq!(lu.len(), n * n);
+        let scale = lu.iter().fold(0.0f64, |acc, v| acc.max(v.abs()));
+        let tiny = scale * 1e-13;
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| {
+                    lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs())
+                })
+                .unwrap_or(k);
+            let value = lu[pivot * n + k].abs();
+            if value <= tiny || value.is_nan() {
+                return Err(SingularPivot {
+                    column: k,
+                    pivot: value,
+                });
+            }
+            pivots.push(pivot);
+            if pivot != k {
+                for col in 0..n {
+                    lu.swap(k * n + col, pivot * n + col);
+                }
+            }
+            let diag = lu[k * n + k];
+            for i in k + 1..n {
+                let f = lu[i * n + k] / diag;
+                lu[i * n + k] = f;
+                if f == 0.0 {
+                    continue;
+                }
+                for col in k + 1..n {
+                    lu[i * n + col] -= f * lu[k * n + col];
+                }
+            }
+        }
+        Ok(Self { lu, pivots })
+    }
+
+    /// Solve `a · x = b`.
+    pub fn solve(&self, mut x: Vec<f64>) -> Vec<f64> {
+        let (lu, n) = (&self.lu, self.pivots.len());
+        debug_assert_eq!(x.len(), n);
+        for (k, &p) in self.pivots.iter().enumerate() {
+            x.swap(k, p);
+        }
+        // Forward substitution: L y = P b (L has unit diagonal).
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| lu[i * n + j] * x[j]).sum();
+            x[i] -= sum;
+        }
+        // Back substitution: U x = y.
+        for i in (0..n).rev() {
+            let sum: f64 =
+                (i + 1..n).map(|j| lu[i * n + j] * x[j]).sum();
+            x[i] = (x[i] - sum) / lu[i * n + i];
+        }
+        x
+    }
+}
+
+/// Solve the dense `n × n` system `a · x = b` (row-major `a`) with
+/// `DenseLu`.
+///
+/// Meant for the small systems of the shape parameterizations; returns
+/// `None` if the matrix is singular to working precision.
+pub fn solve_dense(a: Vec<f64>, b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    if a.len() != n * n {
+        return None;
+    }
+    let x = DenseLu::new(a, n).ok()?.solve(b);
+    x.iter().all(|v| v.is_finite()).then_some(x)
+}
+
+/// Least-squares solution of the over-determined system `rows · x ≈ rhs`
+/// via the normal equations.
+pub fn least_squares(
+    rows: &[Vec<f64>],
+    rhs: &[f64],
+) -> Option<Vec<f64>> {
+    let n = rows.first()?.len();
+    let mut ata = vec![0.0; n * n];
+    let mut atb = vec![0.0; n];
+    for (row, &b) in rows.iter().zip(rhs) {
+        for i in 0..n {
+            atb[i] += row[i] * b;
+            for j in 0..n {
+                ata[i * n + j] += row[i] * row[j];
+            }
+        }
+    }
+    solve_dense(ata, atb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_solve_pivots_and_detects_singular() {
+        let a = vec![0.0, 2.0, 1.0, 1.0];
+        let x = solve_dense(a, vec![4.0, 3.0]).expect("solvable");
+        assert!(
+            (x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12
+        );
+
+        let singular = vec![1.0, 2.0, 2.0, 4.0];
+        assert!(solve_dense(singular, vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn dense_lu_reuses_factors_and_reports_the_singular_column() {
+        let lu = DenseLu::new(vec![0.0, 2.0, 1.0, 1.0], 2).unwrap();
+        for (b, x) in
+            [([4.0, 3.0], [1.0, 2.0]), ([2.0, 2.0], [1.0, 1.0])]
+        {
+            let got = lu.solve(b.to_vec());
+            assert!((got[0] - x[0]).abs() < 1e-12);
+            assert!((got[1] - x[1]).abs() < 1e-12);
+        }
+
+        let err =
+            DenseLu::new(vec![1.0, 2.0, 2.0, 4.0], 2).unwrap_err();
+        assert_eq!(err.column, 1);
+    }
+
+    #[test]
+    fn signed_area_is_positive_counter_clockwise() {
+        let square = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+        assert_eq!(signed_area(&square), 1.0);
+        let mut closed = square.to_vec();
+        closed.push(Vec2::ZERO);
+        closed.reverse();
+        assert_eq!(signed_area(&closed), -1.0);
+    }
+
+    #[test]
+    fn least_squares_recovers_line() {
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let rows: Vec<Vec<f64>> =
+            xs.iter().map(|&x| vec![1.0, x]).collect();
+        let rhs: Vec<f64> = xs.iter().map(|&x| 0.5 + 2.0 * x).collect();
+        let coef = least_squares(&rows, &rhs).expect("fit");
+        assert!((coef[0] - 0.5).abs() < 1e-12);
+        assert!((coef[1] - 2.0).abs() < 1e-12);
+    }
+}
@@ -130,7 +130,14 @@ fn integrate_surface(
         let ue_prime = (ue_curr - ue_prev) / ds;
         let lambda = theta_sq * ue_prime / nu;
 
-        if separation_s.is_none() && lambda < -0.09 && s_curr > 0.02 {
+        // Thwaites' separation criterion only holds while laminar.
+        let laminar_here =
+            transition_s.map(|tr| s_curr <= tr).unwrap_or(true);
+        if separation_s.is_none()
+            && laminar_here
+            && lambda < -0.09
+            && s_curr > 0.02
+        {
             separation_s = Some(s_curr);
             separation_x = Some(x_curr);
         }
@@ -1,7 +1,11 @@
 pub mod boundary_layer;
 pub mod panel;
 pub mod polar;
+pub mod thin_airfoil;
+pub mod validation;
 
 pub use boundary_layer::*;
 pub use panel::*;
 pub use polar::*;
+pub use thin_airfoil::*;
+pub use validation::*;
@@ -0,0 +1,58 @@
+use std::fmt;
+
+use crate::airfoil::GeometryError;
+use crate::math::SingularPivot;
+
+/// Why a panel solve failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolveError {
+    /// The loop is unusable; see `validate_loop`.
+    Geometry(GeometryError),
+    /// Elimination found no usable pivot in `column` of the influence
+    /// matrix; `pivot` is the largest candidate left there.
+    SingularMatrix { column: usize, pivot: f64 },
+    /// The two trailing-edge panels the Kutta condition ties together
+    /// could not be found, e.g. with fewer than two wetted panels.
+    KuttaPanels,
+    /// The solve produced NaN or infinite strengths, speeds or
+    /// coefficients.
+    NonFinite,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Geometry(err) => write!(f, "invalid geometry: {err}"),
+            Self::SingularMatrix { column, pivot } => write!(
+                f,
+                "panel influence matrix is singular (column {column}, \
+                 pivot {pivot:.3e})"
+            ),
+            Self::KuttaPanels => {
+                write!(f, "could not find the trailing-edge panels")
+            }
+            Self::NonFinite => write!(f, "solution is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Geometry(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<GeometryError> for SolveError {
+    fn from(err: GeometryError) -> Self {
+        Self::Geometry(err)
+    }
+}
+
+impl From<SingularPivot> for SolveError {
+    fn from(SingularPivot { column, pivot }: SingularPivot) -> Self {
+        Self::SingularMatrix { column, pivot }
+    }
+}
@@ -1,4 +1,48 @@
-pub(crate) use crate::airfoil::{
-    build_naca_body_geometry_sharp_te, camber_line, camber_slope,
-    thickness_distribution,
-};
+use std::f32::consts::PI;
+
+use crate::airfoil::{AirfoilShape, Surfaces};
+use crate::math::Vec2;
+
+/// Upper/lower surface points at one chordwise sample station.
+pub(crate) struct SurfaceStation {
+    pub(crate) x: f32,
+    pub(crate) upper: Vec2,
+    pub(crate) lower: Vec2,
+}
+
+/// Sample `count` cosine-spaced stations along the chord.
+///
+/// Uses the shape's analytic surfaces when it has them and otherwise
+/// interpolates the coordinate loop `points`.
+pub(crate) fn sample_stations<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
+    points: &[Vec2],
+    count: usize,
+) -> Vec<SurfaceStation> {
+    let count = count.max(2);
+    let mut surfaces: Option<Surfaces> = None;
+    let mut stations = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let beta = i as f32 / (count - 1) as f32;
+        let x_c = 0.5 * (1.0 - (PI * beta).cos());
+        let (upper, lower) =
+            airfoil.analytic_surfaces(x_c).unwrap_or_else(|| {
+                surfaces
+                    .get_or_insert_with(|| Surfaces::from_loop(points))
+                    .at(x_c)
+            });
+        stations.push(SurfaceStation {
+            x: x_c,
+            upper,
+            lower,
+        });
+    }
+
+    stations
+}
+
+/// Number of Cp sample stations for a loop with `loop_len` points.
+pub(crate) fn sample_count(loop_len: usize) -> usize {
+    (loop_len / 4).max(32)
+}
@@ -0,0 +1,357 @@
+//! Linear-vorticity panel method (XFoil's inviscid formulation).
+//!
+//! The surface carries a vortex sheet whose strength varies linearly
+//! between the nodes. With no flow inside the body, the sheet strength
+//! at a node is the surface speed there, positive along the loop. Every
+//! node lies on the dividing streamline ψ = ψ0, and the Kutta condition
+//! makes the two trailing-edge speeds equal (γ1 + γN = 0).
+//!
+//! A blunt trailing edge is closed by a panel with a uniform source and
+//! vortex carrying the mean TE speed off the surface, like `PSILIN`. At
+//! a sharp TE the two end nodes coincide, so the last node's condition
+//! is replaced by zero velocity along the bisector just inside the
+//! corner (`GGCALC`).
+//!
+//! The system is solved once for a freestream along x and once along y;
+//! any angle of attack is a weighted sum of the two. Everything runs in
+//! f64, since the stream function differs little between neighbouring
+//! nodes.
+
+use std::f64::consts::PI;
+
+use crate::airfoil::{GeometryError, dedup_points, surface_run};
+use crate::math::{DVec2, DenseLu, Vec2};
+
+use super::surface::surface_solution;
+use super::{PanelMethod, PanelSolution, SolveError};
+
+/// Offset of the sharp-TE bisector point, in lengths of the shorter TE
+/// panel (XFoil's `BWT`).
+const BISECTOR_OFFSET: f64 = 0.1;
+
+/// TE gap, as a fraction of the chord, below which the TE is sharp.
+const SHARP_TE_GAP: f64 = 1e-4;
+
+/// Straight panel between two nodes. The normal points to the left of
+/// the tangent, out of the body for loops in solver ordering.
+struct LinePanel {
+    start: DVec2,
+    tangent: DVec2,
+    normal: DVec2,
+    length: f64,
+}
+
+impl LinePanel {
+    fn new(start: DVec2, end: DVec2) -> Self {
+        let d = end - start;
+        let length = d.length();
+        let tangent = d / length;
+        Self {
+            start,
+            tangent,
+            normal: tangent.perp(),
+            length,
+        }
+    }
+}
+
+/// A field point in a panel's frame, with its distances and angles from
+/// the two panel ends.
+struct Local {
+    x: f64,
+    y: f64,
+    length: f64,
+    r1: f64,
+    r2: f64,
+    t1: f64,
+    t2: f64,
+}
+
+impl Local {
+    fn new(panel: &LinePanel, p: DVec2) -> Self {
+        let d = p - panel.start;
+        let x = d.dot(panel.tangent);
+        let mut y = d.dot(panel.normal);
+        // Points on the panel line are seen from inside the body, which
+        // keeps every node on one branch of the TE source's ψ.
+        if y.abs() <= 1e-12 * panel.length {
+            y = -0.0;
+        }
+        let x2 = x - panel.length;
+        Self {
+            x,
+            y,
+            length: panel.length,
+            r1: x.hypot(y),
+            r2: x2.hypot(y),
+            t1: y.atan2(x),
+            t2: y.atan2(x2),
+        }
+    }
+
+    /// `y ln(r1 / r2)`, zero on the panel line.
+    fn y_log_ratio(&self) -> f64 {
+        if self.y == 0.0 {
+            0.0
+        } else {
+            self.y * (self.r1.ln() - self.r2.ln())
+        }
+    }
+
+    /// `∫ ln r ds` over the panel.
+    fn log_integral(&self) -> f64 {
+        x_ln_r(self.x, self.r1)
+            - x_ln_r(self.x - self.length, self.r2)
+            - self.length
+            + self.y * (self.t2 - self.t1)
+    }
+
+    /// `∫ s ln r ds` over the panel, given `log_integral`.
+    fn log_moment(&self, log_integral: f64) -> f64 {
+        let f = |r: f64| 0.5 * x_ln_r(r * r, r) - 0.25 * r * r;
+        self.x * log_integral - (f(self.r1) - f(self.r2))
+    }
+
+    /// `∫ θ ds` over the panel, θ being the angle seen from each point.
+    fn angle_integral(&self) -> f64 {
+        self.x * self.t1 - (self.x - self.length) * self.t2
+            + self.y_log_ratio()
+    }
+
+    /// `ln(r1 / r2)` for velocities, finite at the panel ends.
+    fn log_ratio(&self) -> f64 {
+        self.r1.max(1e-12).ln() - self.r2.max(1e-12).ln()
+    }
+}
+
+fn x_ln_r(x: f64, r: f64) -> f64 {
+    if r > 0.0 { x * r.ln() } else { 0.0 }
+}
+
+/// The closing panel of a blunt TE and the share of the mean TE speed
+/// carried by its source and vortex.
+struct TePanel {
+    panel: LinePanel,
+    /// `s · n`: source strength per unit mean TE speed.
+    source: f64,
+    /// `s · t`: vortex strength per unit mean TE speed.
+    vortex: f64,
+}
+
+/// Factorized linear-vorticity system for one loop.
+pub(crate) struct LinearVortexSystem {
+    nodes: Vec<DVec2>,
+    panels: Vec<LinePanel>,
+    te: Option<TePanel>,
+    /// Node speeds for a unit freestream along x and along y.
+    basis: [Vec<f64>; 2],
+}
+
+impl LinearVortexSystem {
+    /// Build and solve the system for a closed loop in solver ordering.
+    pub(crate) fn new(points: &[Vec2]) -> Result<Self, SolveError> {
+        let (run, _) = surface_run(points);
+        let nodes: Vec<DVec2> = dedup_points(run, 1e-7)
+            .iter()
+            .map(|p| p.as_dvec2())
+            .collect();
+        let n = nodes.len();
+        if n < 5 {
+            return Err(GeometryError::TooFewPoints { found: n }.into());
+        }
+
+        let (min_x, max_x) =
+            nodes.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.x), hi.max(p.x))
+            });
+        let chord = max_x - min_x;
+        let sharp =
+            nodes[0].distance(nodes[n - 1]) < SHARP_TE_GAP * chord;
+
+        let panels: Vec<LinePanel> = nodes
+            .windows(2)
+            .map(|w| LinePanel::new(w[0], w[1]))
+            .collect();
+        let upper_dir = (nodes[n - 1] - nodes[n - 2]).normalize();
+        let lower_dir = (nodes[0] - nodes[1]).normalize();
+        if !upper_dir.is_finite() || !lower_dir.is_finite() {
+            return Err(SolveError::KuttaPanels);
+        }
+        let bisector = (upper_dir + lower_dir).normalize_or(DVec2::X);
+        let te = (!sharp).then(|| {
+            let panel = LinePanel::new(nodes[n - 1], nodes[0]);
+            TePanel {
+                source: bisector.dot(panel.normal),
+                vortex: bisector.dot(panel.tangent),
+                panel,
+            }
+        });
+
+        let mut system = Self {
+            nodes,
+            panels,
+            te,
+            basis: [Vec::new(), Vec::new()],
+        };
+
+        // Unknowns: the node speeds, then ψ0.
+        let size = n + 1;
+        let mut matrix = vec![0.0; size * size];
+        let mut rhs_x = vec![0.0; size];
+        let mut rhs_y = vec![0.0; size];
+        for (i, &p) in system.nodes.iter().enumerate() {
+            let row = &mut matrix[i * size..(i + 1) * size];
+            system.add_stream_function(p, row);
+            row[n] = -1.0;
+            // ψ∞ = V∞x y - V∞y x moves to the right-hand side.
+            rhs_x[i] = -p.y;
+            rhs_y[i] = p.x;
+        }
+        if sharp {
+            let ds = system.panels[0]
+                .length
+                .min(system.panels[n - 2].length);
+            let point =
+                system.nodes[0] - BISECTOR_OFFSET * ds * bisector;
+            let row = &mut matrix[(n - 1) * size..n * size];
+            row.fill(0.0);
+            for (j, v) in system.node_velocities(point) {
+                row[j] += v.dot(bisector);
+            }
+            rhs_x[n - 1] = -bisector.x;
+            rhs_y[n - 1] = -bisector.y;
+        }
+        // Kutta: equal speeds leaving the two TE nodes.
+        matrix[n * size] = 1.0;
+        matrix[n * size + n - 1] = 1.0;
+
+        let lu = DenseLu::new(matrix, size)?;
+        for (basis, rhs) in system.basis.iter_mut().zip([rhs_x, rhs_y])
+        {
+            let mut gamma = lu.solve(rhs);
+            gamma.truncate(n);
+            if gamma.iter().any(|g| !g.is_finite()) {
+                return Err(SolveError::NonFinite);
+            }
+            *basis = gamma;
+        }
+        Ok(system)
+    }
+
+    /// Surface speed at each node, positive along the loop.
+    pub(crate) fn node_speeds(&self, freestream: Vec2) -> Vec<f64> {
+        let v = freestream.as_dvec2();
+        self.basis[0]
+            .iter()
+            .zip(&self.basis[1])
+            .map(|(gx, gy)| v.x * gx + v.y * gy)
+            .collect()
+    }
+
+    /// Velocity induced at `point` by the sheet with node speeds
+    /// `gamma`.
+    pub(crate) fn induced_velocity(
+        &self,
+        point: Vec2,
+        gamma: &[f64],
+    ) -> Vec2 {
+        self.node_velocities(point.as_dvec2())
+            .map(|(j, v)| gamma[j] * v)
+            .sum::<DVec2>()
+            .as_vec2()
+    }
+
+    /// Add each node speed's contribution to ψ at `p` into `row`.
+    fn add_stream_function(&self, p: DVec2, row: &mut [f64]) {
+        let scale = 1.0 / (2.0 * PI);
+        for (k, panel) in self.panels.iter().enumerate() {
+            let local = Local::new(panel, p);
+            let i0 = local.log_integral();
+            let i1 = local.log_moment(i0) / panel.length;
+            row[k] += scale * (i0 - i1);
+            row[k + 1] += scale * i1;
+        }
+        if let Some(te) = &self.te {
+            let local = Local::new(&te.panel, p);
+            let psi = scale
+                * (te.vortex * local.log_integral()
+                    + te.source * local.angle_integral());
+            self.add_te_share(psi, row);
+        }
+    }
+
+    /// Velocity at `p` per unit speed of each node that affects it.
+    fn node_velocities(
+        &self,
+        p: DVec2,
+    ) -> impl Iterator<Item = (usize, DVec2)> + '_ {
+        let scale = 1.0 / (2.0 * PI);
+        let n = self.nodes.len();
+        let surface = self.panels.iter().enumerate().flat_map(
+            move |(k, panel)| {
+                let local = Local::new(panel, p);
+                let i0 = local.log_integral();
+                let ln12 = local.log_ratio();
+                let dt = local.t2 - local.t1;
+                let d = (local.x * dt - local.y * ln12) / panel.length;
+                let ln1 = local.r1.max(1e-12).ln();
+                let ln2 = local.r2.max(1e-12).ln();
+                let at = |dx: f64, dy: f64| {
+                    scale * (dy * panel.tangent - dx * panel.normal)
+                };
+                [
+                    (k, at(ln1 - i0 / panel.length, dt - d)),
+                    (k + 1, at(i0 / panel.length - ln2, d)),
+                ]
+            },
+        );
+        let te = self.te.iter().flat_map(move |te| {
+            let local = Local::new(&te.panel, p);
+            let ln12 = local.log_ratio();
+            let dt = local.t2 - local.t1;
+            let (t, nrm) = (te.panel.tangent, te.panel.normal);
+            let v = scale
+                * (te.vortex * (dt * t - ln12 * nrm)
+                    + te.source * (ln12 * t + dt * nrm));
+            // The mean TE speed is (γN - γ1) / 2.
+            [(n - 1, 0.5 * v), (0, -0.5 * v)]
+        });
+        surface.chain(te)
+    }
+
+    fn add_te_share(&self, value: f64, row: &mut [f64]) {
+        let n = self.nodes.len();
+        row[n - 1] += 0.5 * value;
+        row[0] -= 0.5 * value;
+    }
+
+    /// Surface speed and Cp at the nodes.
+    pub(crate) fn panel_solution(
+        &self,
+        alpha_deg: f32,
+    ) -> PanelSolution {
+        let alpha_rad = alpha_deg.to_radians();
+        let freestream = Vec2::new(alpha_rad.cos(), alpha_rad.sin());
+        let nodes: Vec<Vec2> =
+            self.nodes.iter().map(|p| p.as_vec2()).collect();
+        let arc_length = std::iter::once(0.0)
+            .chain(self.panels.iter().scan(0.0, |s, panel| {
+                *s += panel.length;
+                Some(*s as f32)
+            }))
+            .collect();
+        let speeds = self
+            .node_speeds(freestream)
+            .iter()
+            .map(|&g| g as f32)
+            .collect();
+        surface_solution(
+            &nodes,
+            nodes.clone(),
+            arc_length,
+            speeds,
+            alpha_deg,
+            PanelMethod::LinearVorticity,
+        )
+    }
+}
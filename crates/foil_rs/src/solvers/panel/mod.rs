@@ -1,26 +1,36 @@
 use std::f32::consts::PI;
 
-use crate::math::Vec2;
-use crate::state::NacaParams;
+use crate::airfoil::{
+    AirfoilShape, GeometryError, GeometryWarning, validate_loop,
+};
+use crate::math::{DenseLu, Vec2, signed_area};
+
+use super::thin_airfoil::ThinAirfoil;
 
+mod error;
 mod geometry;
+mod linear_vortex;
 mod panels;
+mod surface;
 
-use geometry::{
-    build_naca_body_geometry_sharp_te, camber_line, camber_slope,
-    thickness_distribution,
-};
+use geometry::{sample_count, sample_stations};
+use linear_vortex::LinearVortexSystem;
 use panels::{Panel, build_panels};
+use surface::{circulation, integrate_forces, surface_solution};
 
-const SURFACE_SAMPLE_EPS: f32 = 1e-4;
-const COLLOCATION_OFFSET: f32 = 1e-4;
+pub use error::SolveError;
+pub use surface::SectionForces;
 
-fn effective_num_points(params: &NacaParams) -> usize {
-    let n = params.num_points.max(32);
-    if n.is_multiple_of(2) { n } else { n + 1 }
-}
+const COLLOCATION_OFFSET: f32 = 1e-4;
 
-/// Result of our pseudo-panel solution.
+/// Surface flow and coefficients at one α: a panel solve, or the
+/// camber-line estimate when none was possible (see
+/// `coefficient_source`).
+///
+/// Every solver follows XFoil's conventions: the upper surface is the one
+/// above the chord line (the suction side at positive lift), positive CL
+/// points up at positive α, and CM is positive nose-up. Cp is plotted
+/// negative-up; see `cp_plot_y`.
 pub struct PanelSolution {
     /// x / c for each sample, 0..1.
     pub x: Vec<f32>,
@@ -32,12 +42,33 @@ pub struct PanelSolution {
     pub upper_coords: Vec<Vec2>,
     /// Coordinate of each sample on lower surface.
     pub lower_coords: Vec<Vec2>,
+    /// Points where the solver evaluates the surface flow, in solver
+    /// loop order (TE → lower → LE → upper): panel midpoints for the
+    /// constant-source method, nodes for linear vorticity. Empty for
+    /// approximate solutions.
+    pub surface_points: Vec<Vec2>,
+    /// Distance along the loop to each surface point.
+    pub arc_length: Vec<f32>,
+    /// Surface speed at each surface point, positive along the loop
+    /// (U∞ = 1).
+    pub tangential_velocity: Vec<f32>,
+    /// Cp at each surface point, unclamped.
+    pub cp: Vec<f32>,
+    /// Angle of attack the solution is for.
+    pub alpha_deg: f32,
+    /// Point `cm_c4` is taken about.
+    pub(crate) quarter_chord: Vec2,
+    /// LE → TE chord the coefficients are based on.
+    pub(crate) chord: f32,
+    /// What `cl` and `cm_c4` come from.
+    pub coefficient_source: CoefficientSource,
     pub(crate) cl_cached: Option<f32>,
     pub(crate) cm_c4_cached: Option<f32>,
 }
 
 impl PanelSolution {
-    /// Approximate section lift coefficient by integrating Cp difference.
+    /// Section lift coefficient; `coefficient_source` says how it was
+    /// obtained.
     pub fn cl(&self) -> Option<f32> {
         if let Some(cl) = self.cl_cached {
             return Some(cl);
@@ -58,7 +89,7 @@ impl PanelSolution {
         Some(cl)
     }
 
-    /// Approximate pitching moment about c/4 (sign convention: nose-up positive).
+    /// Pitching moment about c/4, nose-up positive.
     pub fn cm_c4(&self) -> Option<f32> {
         if let Some(cm) = self.cm_c4_cached {
             return Some(cm);
@@ -82,468 +113,583 @@ impl PanelSolution {
         }
         Some(-cm)
     }
-}
 
-fn integrate_cl_from_cp(
-    x: &[f32],
-    cp_upper: &[f32],
-    cp_lower: &[f32],
-) -> Option<f32> {
-    if x.len() < 2
-        || x.len() != cp_upper.len()
-        || x.len() != cp_lower.len()
-    {
-        return None;
+    /// Forces from Cp integrated along the solver surface, with the
+    /// moment about `reference`. `None` for approximate solutions.
+    pub fn surface_forces(
+        &self,
+        reference: Vec2,
+    ) -> Option<SectionForces> {
+        integrate_forces(
+            &self.surface_points,
+            &self.cp,
+            self.alpha_deg.to_radians(),
+            reference,
+            self.chord,
+        )
     }
-    let mut cl = 0.0;
-    for i in 0..x.len() - 1 {
-        let dx = x[i + 1] - x[i];
-        if dx <= 0.0 {
-            continue;
+
+    /// Pitching moment about `reference`, nose-up positive.
+    ///
+    /// The reference is in the section's own coordinates: a unit chord
+    /// from the origin for the built-in shapes. Panel solutions integrate
+    /// Cp about it directly; approximate ones move `cm_c4` there.
+    pub fn cm_about(&self, reference: Vec2) -> Option<f32> {
+        if let Some(forces) = self.surface_forces(reference) {
+            return Some(forces.cm);
         }
-        let dcp0 = cp_lower[i] - cp_upper[i];
-        let dcp1 = cp_lower[i + 1] - cp_upper[i + 1];
-        cl += 0.5 * (dcp0 + dcp1) * dx;
-    }
-    Some(cl)
-}
-
-fn integrate_cm_c4_from_cp(
-    x: &[f32],
-    cp_upper: &[f32],
-    cp_lower: &[f32],
-) -> Option<f32> {
-    if x.len() < 2
-        || x.len() != cp_upper.len()
-        || x.len() != cp_lower.len()
-    {
-        return None;
-    }
-    let mut cm = 0.0;
-    for i in 0..x.len() - 1 {
-        let x0 = x[i];
-        let x1 = x[i + 1];
-        let dx = x1 - x0;
-        if dx <= 0.0 {
-            continue;
+        Some(transfer_moment(
+            self.cm_c4()?,
+            self.cl()?,
+            self.alpha_deg,
+            self.quarter_chord,
+            reference,
+            self.chord,
+        ))
+    }
+
+    /// Center of pressure as a fraction of the chord from the LE.
+    pub fn x_cp(&self) -> Option<f32> {
+        center_of_pressure(self.cl()?, self.cm_c4()?)
+    }
+
+    /// Lift from the circulation round the surface, CL = 2Γ / (U∞ c):
+    /// a cross-check on the pressure-integrated `cl`.
+    pub fn kutta_joukowski_cl(&self) -> Option<f32> {
+        circulation(&self.surface_points, &self.tangential_velocity)
+            .map(|gamma| 2.0 * gamma / self.chord)
+    }
+}
+
+/// Move a moment coefficient from `from` to `to`, with the lift acting
+/// at `from` and no drag; both coefficients are based on `chord`.
+pub(crate) fn transfer_moment(
+    cm: f32,
+    cl: f32,
+    alpha_deg: f32,
+    from: Vec2,
+    to: Vec2,
+    chord: f32,
+) -> f32 {
+    let (sa, ca) = alpha_deg.to_radians().sin_cos();
+    let force = Vec2::new(-cl * sa, cl * ca);
+    // Nose-up is clockwise.
+    cm - (from - to).perp_dot(force) / chord
+}
+
+/// Chordwise center of pressure, x/c = 1/4 - CM_c/4 / CL; `None` near
+/// zero lift, where it runs off to infinity.
+pub fn center_of_pressure(cl: f32, cm_c4: f32) -> Option<f32> {
+    (cl.abs() > 1e-3).then(|| 0.25 - cm_c4 / cl)
+}
+
+/// How a solution's or polar row's coefficients were obtained.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CoefficientSource {
+    /// Cp integrated along the surface of a panel solution.
+    SurfacePressure(PanelMethod),
+    /// Estimated from the camber line; no panel solve.
+    Approximate,
+}
+
+impl CoefficientSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::SurfacePressure(PanelMethod::ConstantSource) => {
+                "Surface pressure (constant source)"
+            }
+            Self::SurfacePressure(PanelMethod::LinearVorticity) => {
+                "Surface pressure (linear vorticity)"
+            }
+            Self::Approximate => "Approximate",
         }
-        let x_avg = 0.5 * (x0 + x1);
-        let dcp0 = cp_lower[i] - cp_upper[i];
-        let dcp1 = cp_lower[i + 1] - cp_upper[i + 1];
-        let dcp_avg = 0.5 * (dcp0 + dcp1);
-        cm += dcp_avg * dx * (x_avg - 0.25);
     }
-    Some(-cm)
 }
 
+/// Plot ordinate for a Cp value: Cp axes point down, as in XFoil, so
+/// suction is drawn above the axis.
+pub fn cp_plot_y(cp: f32) -> f32 {
+    -cp
+}
+
+/// Inviscid formulation behind a `PanelLuSystem`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PanelMethod {
+    /// Constant-strength sources per panel plus one vortex strength
+    /// shared by all of them (Hess–Smith); the surface speed is taken
+    /// just off each panel's midpoint.
+    #[default]
+    ConstantSource,
+    /// Vorticity varying linearly between the nodes with a
+    /// stream-function boundary condition, as in XFoil; the node
+    /// strengths are the surface speeds.
+    LinearVorticity,
+}
+
+impl PanelMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ConstantSource => "Constant source",
+            Self::LinearVorticity => "Linear vorticity",
+        }
+    }
+}
+
+/// Panel system for one shape, solved for a unit freestream along x and
+/// along y.
+///
+/// The flow is linear in the freestream, so any angle of attack is a
+/// weighted sum of the two: solutions, flows and polar points cost O(N)
+/// per α once the system is built.
 pub struct PanelLuSystem {
+    warnings: Vec<GeometryWarning>,
+    solver: Solver,
+}
+
+enum Solver {
+    ConstantSource(SourceSystem),
+    LinearVorticity(LinearVortexSystem),
+}
+
+/// Hess–Smith basis solutions.
+struct SourceSystem {
+    points: Vec<Vec2>,
     panels: Vec<Panel>,
-    lu: Vec<f32>,
-    pivots: Vec<usize>,
-    size: usize,
-    upper_dir: Vec2,
-    lower_dir: Vec2,
+    /// Wetted panel midpoints and their distance along the loop.
+    surface: Vec<Vec2>,
+    arc_length: Vec<f32>,
+    /// For a freestream along x, then along y.
+    basis: [SourceBasis; 2],
 }
 
-pub struct PanelFlow<'a> {
-    panels: &'a [Panel],
+/// Strengths and surface speeds for one unit freestream.
+struct SourceBasis {
     sources: Vec<f32>,
     gamma: f32,
+    /// Speed along each wetted panel, just off its midpoint.
+    speeds: Vec<f32>,
+}
+
+pub struct PanelFlow<'a> {
+    strengths: FlowStrengths<'a>,
     freestream: Vec2,
 }
 
+enum FlowStrengths<'a> {
+    ConstantSource {
+        panels: &'a [Panel],
+        sources: Vec<f32>,
+        gamma: f32,
+    },
+    LinearVorticity {
+        system: &'a LinearVortexSystem,
+        gamma: Vec<f64>,
+    },
+}
+
 impl PanelFlow<'_> {
     pub fn velocity_body_pg(&self, point: Vec2, mach: f32) -> Vec2 {
         let beta = (1.0 - mach * mach).clamp(0.05, 1.0).sqrt();
-        let induced = induced_velocity_from_solution(
-            point,
-            self.panels,
-            &self.sources,
-            self.gamma,
-        );
+        let induced = match &self.strengths {
+            FlowStrengths::ConstantSource {
+                panels,
+                sources,
+                gamma,
+            } => induced_velocity_from_solution(
+                point, panels, sources, *gamma,
+            ),
+            FlowStrengths::LinearVorticity { system, gamma } => {
+                system.induced_velocity(point, gamma)
+            }
+        };
         self.freestream + induced / beta
     }
 }
 
 impl PanelLuSystem {
-    pub fn new(params: &NacaParams) -> Option<Self> {
-        let mut local = params.clone();
-        local.num_points = effective_num_points(params);
-        let geometry = build_naca_body_geometry_sharp_te(&local);
-        let panels = build_panels(&geometry);
-        if panels.len() < 4 {
-            return None;
+    /// Factorize the influence matrix for the shape's panel loop.
+    ///
+    /// Panels follow the loop nodes one to one; wrap the shape in
+    /// `airfoil::Repaneled` to redistribute them by curvature first.
+    /// Returns `None` for unusable geometry or a failed factorization;
+    /// see `try_new` for the reason.
+    pub fn new<A: AirfoilShape + ?Sized>(airfoil: &A) -> Option<Self> {
+        Self::try_new(airfoil).ok()
+    }
+
+    /// Like `new`, but reports why the system could not be built.
+    ///
+    /// Non-fatal findings are kept and available from `warnings`.
+    pub fn try_new<A: AirfoilShape + ?Sized>(
+        airfoil: &A,
+    ) -> Result<Self, SolveError> {
+        Self::try_with_method(airfoil, PanelMethod::default())
+    }
+
+    /// Like `new`, with a choice of inviscid formulation.
+    pub fn with_method<A: AirfoilShape + ?Sized>(
+        airfoil: &A,
+        method: PanelMethod,
+    ) -> Option<Self> {
+        Self::try_with_method(airfoil, method).ok()
+    }
+
+    /// Like `try_new`, with a choice of inviscid formulation.
+    pub fn try_with_method<A: AirfoilShape + ?Sized>(
+        airfoil: &A,
+        method: PanelMethod,
+    ) -> Result<Self, SolveError> {
+        let points = airfoil.panel_points();
+        let warnings = validate_loop(&points)?;
+        Self::build(&points, warnings, method)
+    }
+
+    /// Build without validating the loop, for solves that should still
+    /// try on geometry `try_new` rejects.
+    pub(crate) fn unchecked<A: AirfoilShape + ?Sized>(
+        airfoil: &A,
+        method: PanelMethod,
+    ) -> Result<Self, SolveError> {
+        let points = airfoil.panel_points();
+        if points.len() < 5 {
+            return Err(GeometryError::TooFewPoints {
+                found: points.len(),
+            }
+            .into());
         }
+        Self::build(&points, Vec::new(), method)
+    }
+
+    fn build(
+        points: &[Vec2],
+        warnings: Vec<GeometryWarning>,
+        method: PanelMethod,
+    ) -> Result<Self, SolveError> {
+        // The Kutta panels and a blunt TE base are found by position in
+        // TE → lower → LE → upper order, so run a reversed loop backwards.
+        let reversed: Vec<Vec2>;
+        let points = if signed_area(points) > 0.0 {
+            reversed = points.iter().rev().copied().collect();
+            &reversed
+        } else {
+            points
+        };
+        let solver = match method {
+            PanelMethod::ConstantSource => {
+                Solver::ConstantSource(SourceSystem::new(points)?)
+            }
+            PanelMethod::LinearVorticity => Solver::LinearVorticity(
+                LinearVortexSystem::new(points)?,
+            ),
+        };
+        Ok(Self { warnings, solver })
+    }
 
-        let (matrix, size, upper_dir, lower_dir) =
-            assemble_matrix(&panels);
-        let (lu, pivots) = lu_factorize(&matrix, size)?;
+    /// Geometry warnings found while building the system.
+    pub fn warnings(&self) -> &[GeometryWarning] {
+        &self.warnings
+    }
 
-        Some(Self {
-            panels,
-            lu,
-            pivots,
-            size,
-            upper_dir,
-            lower_dir,
-        })
+    /// The inviscid formulation this system was built with.
+    pub fn method(&self) -> PanelMethod {
+        match self.solver {
+            Solver::ConstantSource(_) => PanelMethod::ConstantSource,
+            Solver::LinearVorticity(_) => PanelMethod::LinearVorticity,
+        }
     }
 
-    pub fn solve_flow(&self, alpha_deg: f32) -> Option<PanelFlow<'_>> {
-        let alpha_rad = alpha_deg.to_radians();
-        let freestream = Vec2::new(alpha_rad.cos(), alpha_rad.sin());
-        let (sources, gamma) = self.solve(freestream)?;
-        Some(PanelFlow {
-            panels: &self.panels,
-            sources,
-            gamma,
+    pub fn solve_flow(&self, alpha_deg: f32) -> PanelFlow<'_> {
+        let freestream = freestream(alpha_deg);
+        let strengths = match &self.solver {
+            Solver::ConstantSource(system) => {
+                let (sources, gamma) = system.strengths(freestream);
+                FlowStrengths::ConstantSource {
+                    panels: &system.panels,
+                    sources,
+                    gamma,
+                }
+            }
+            Solver::LinearVorticity(system) => {
+                FlowStrengths::LinearVorticity {
+                    system,
+                    gamma: system.node_speeds(freestream),
+                }
+            }
+        };
+        PanelFlow {
+            strengths,
             freestream,
-        })
+        }
     }
 
-    pub(crate) fn solve(
-        &self,
-        freestream: Vec2,
-    ) -> Option<(Vec<f32>, f32)> {
-        let rhs = assemble_rhs(
-            &self.panels,
-            freestream,
-            self.upper_dir,
-            self.lower_dir,
-        );
-        let strengths =
-            lu_solve(&self.lu, &self.pivots, &rhs, self.size)?;
-        if strengths.len() != self.size {
-            return None;
+    /// Surface speed, Cp and coefficients at `alpha_deg`, all taken on
+    /// the panels of the shape the system was built for.
+    pub fn panel_solution(&self, alpha_deg: f32) -> PanelSolution {
+        match &self.solver {
+            Solver::ConstantSource(system) => {
+                system.panel_solution(alpha_deg)
+            }
+            Solver::LinearVorticity(system) => {
+                system.panel_solution(alpha_deg)
+            }
         }
-        let n_panels = self.panels.len();
-        let gamma = strengths[n_panels];
-        let sources = strengths[..n_panels].to_vec();
-        Some((sources, gamma))
     }
 
-    pub fn panel_solution(
+    /// Like `panel_solution`, but fails instead of returning NaN
+    /// speeds or coefficients (e.g. for a non-finite `alpha_deg`).
+    pub fn try_panel_solution(
         &self,
-        params: &NacaParams,
         alpha_deg: f32,
-    ) -> PanelSolution {
-        let alpha_rad = alpha_deg.to_radians();
-        let freestream = Vec2::new(alpha_rad.cos(), alpha_rad.sin());
-
-        let Some((sources, gamma)) = self.solve(freestream) else {
-            let (cl, cm_c4, _) =
-                approx_section_coeffs(params, alpha_deg);
-            return PanelSolution {
-                x: Vec::new(),
-                cp_upper: Vec::new(),
-                cp_lower: Vec::new(),
-                upper_coords: Vec::new(),
-                lower_coords: Vec::new(),
-                cl_cached: Some(cl),
-                cm_c4_cached: Some(cm_c4),
+    ) -> Result<PanelSolution, SolveError> {
+        let sol = self.panel_solution(alpha_deg);
+        let finite = sol.cp.iter().all(|cp| cp.is_finite())
+            && [sol.cl(), sol.cm_c4()]
+                .iter()
+                .all(|c| c.is_some_and(f32::is_finite));
+        if finite {
+            Ok(sol)
+        } else {
+            Err(SolveError::NonFinite)
+        }
+    }
+}
+
+/// Unit freestream in body coordinates. The views rotate the airfoil in
+/// world space instead, so here the freestream turns with α.
+fn freestream(alpha_deg: f32) -> Vec2 {
+    let alpha_rad = alpha_deg.to_radians();
+    Vec2::new(alpha_rad.cos(), alpha_rad.sin())
+}
+
+impl SourceSystem {
+    fn new(points: &[Vec2]) -> Result<Self, SolveError> {
+        let panels = build_panels(points);
+        let wetted = || panels.iter().filter(|p| p.te_base.is_none());
+        let surface: Vec<Vec2> =
+            wetted().map(|panel| panel.mid).collect();
+        let arc_length = wetted()
+            .scan(0.0, |s, panel| {
+                *s += panel.length;
+                Some(*s - 0.5 * panel.length)
+            })
+            .collect();
+        let (matrix, size, upper_dir, lower_dir) =
+            assemble_matrix(&panels)?;
+        let lu = DenseLu::new(
+            matrix.iter().map(|&v| f64::from(v)).collect(),
+            size,
+        )?;
+
+        let basis = [Vec2::X, Vec2::Y].map(|freestream| {
+            let rhs =
+                assemble_rhs(&panels, freestream, upper_dir, lower_dir);
+            let strengths: Vec<f32> = lu
+                .solve(rhs.iter().map(|&v| f64::from(v)).collect())
+                .into_iter()
+                .map(|v| v as f32)
+                .collect();
+            let (sources, gamma) = (
+                strengths[..panels.len()].to_vec(),
+                strengths[size - 1],
+            );
+            let velocity = |point: Vec2| {
+                freestream
+                    + induced_velocity_from_solution(
+                        point, &panels, &sources, gamma,
+                    )
             };
-        };
+            let speeds: Vec<f32> = wetted()
+                .zip(&surface)
+                .map(|(panel, &mid)| {
+                    let off = mid + panel.normal * COLLOCATION_OFFSET;
+                    velocity(off).dot(panel.tangent)
+                })
+                .collect();
+            if !speeds.iter().all(|u| u.is_finite()) {
+                return Err(SolveError::NonFinite);
+            }
+            Ok(SourceBasis {
+                sources,
+                gamma,
+                speeds,
+            })
+        });
+        let [x, y] = basis;
+        Ok(Self {
+            points: points.to_vec(),
+            panels,
+            surface,
+            arc_length,
+            basis: [x?, y?],
+        })
+    }
 
-        let mut local = params.clone();
-        local.num_points = effective_num_points(params);
-        build_panel_solution_from_strengths(
-            &local,
+    fn strengths(&self, freestream: Vec2) -> (Vec<f32>, f32) {
+        let [x, y] = &self.basis;
+        let sources = x
+            .sources
+            .iter()
+            .zip(&y.sources)
+            .map(|(sx, sy)| freestream.x * sx + freestream.y * sy)
+            .collect();
+        (sources, freestream.x * x.gamma + freestream.y * y.gamma)
+    }
+
+    fn panel_solution(&self, alpha_deg: f32) -> PanelSolution {
+        let v = freestream(alpha_deg);
+        let [x, y] = &self.basis;
+        let speeds = x
+            .speeds
+            .iter()
+            .zip(&y.speeds)
+            .map(|(ux, uy)| v.x * ux + v.y * uy)
+            .collect();
+        surface_solution(
+            &self.points,
+            self.surface.clone(),
+            self.arc_length.clone(),
+            speeds,
             alpha_deg,
-            freestream,
-            &self.panels,
-            &sources,
-            gamma,
+            PanelMethod::ConstantSource,
         )
     }
 }
 
-/// Approximate section coefficients (thin-airfoil-inspired).
+/// Approximate section coefficients `(cl, cm_c4, cdp)` from thin-airfoil
+/// theory on the shape's mean line; profile drag is not modeled.
 ///
 /// This is used as a *fallback* when the panel solver fails, and as the basis
 /// for the explicit "Approx" mode in the UI.
-pub fn approx_section_coeffs(
-    params: &NacaParams,
+pub fn approx_section_coeffs<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> (f32, f32, f32) {
-    // Zero-lift angle scales with camber; tuned so NACA 2412 gives ~0.255 CL at 0°,
-    // while also keeping CL(±4°) in a sane range for our UI/tests.
-    let alpha0_lift_deg = -92.0 * params.m(); // m in chord fractions
-    let alpha_eff_rad = (alpha_deg - alpha0_lift_deg).to_radians();
-    let cl_slope_scale = 1.27;
-    let cl =
-        cl_slope_scale * 2.0 * std::f32::consts::PI * alpha_eff_rad;
-
-    // Rough Cm about c/4 scaling with camber; tuned for 2412 ≈ -0.055.
-    let cm_c4 = -2.5 * params.m();
-
-    // Placeholder for profile drag; not modeled yet.
-    let cdp = 0.0;
-
-    (cl, cm_c4, cdp)
+    let thin = ThinAirfoil::from_airfoil(airfoil);
+    (thin.cl(alpha_deg), thin.cm_c4(), 0.0)
 }
 
 /// Backwards-compatible name for `approx_section_coeffs`.
-pub fn analytic_section_coeffs(
-    params: &NacaParams,
+pub fn analytic_section_coeffs<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> (f32, f32, f32) {
-    approx_section_coeffs(params, alpha_deg)
+    approx_section_coeffs(airfoil, alpha_deg)
 }
 
-/// Simple constant-strength vortex panel method with a Kutta condition.
-pub fn compute_panel_solution(
-    params: &NacaParams,
+/// Solution with no Cp samples, carrying approximate coefficients only.
+fn empty_solution<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> PanelSolution {
-    let alpha_rad = alpha_deg.to_radians();
-
-    // Freestream in body coordinates; visualization rotates the airfoil in world
-    // space, so in body coordinates the freestream rotates with alpha.
-    let freestream = Vec2::new(alpha_rad.cos(), alpha_rad.sin());
-
-    let mut local = params.clone();
-    local.num_points = effective_num_points(params);
-    let geometry = build_naca_body_geometry_sharp_te(&local);
-    let panels = build_panels(&geometry);
-
-    if panels.len() < 4 {
-        let (cl, cm_c4, _) = approx_section_coeffs(params, alpha_deg);
-        return PanelSolution {
-            x: Vec::new(),
-            cp_upper: Vec::new(),
-            cp_lower: Vec::new(),
-            upper_coords: Vec::new(),
-            lower_coords: Vec::new(),
-            cl_cached: Some(cl),
-            cm_c4_cached: Some(cm_c4),
-        };
+    let (cl, cm_c4, _) = approx_section_coeffs(airfoil, alpha_deg);
+    PanelSolution {
+        x: Vec::new(),
+        cp_upper: Vec::new(),
+        cp_lower: Vec::new(),
+        upper_coords: Vec::new(),
+        lower_coords: Vec::new(),
+        surface_points: Vec::new(),
+        arc_length: Vec::new(),
+        tangential_velocity: Vec::new(),
+        cp: Vec::new(),
+        alpha_deg,
+        quarter_chord: Vec2::new(0.25, 0.0),
+        chord: 1.0,
+        coefficient_source: CoefficientSource::Approximate,
+        cl_cached: Some(cl),
+        cm_c4_cached: Some(cm_c4),
     }
+}
 
-    let system = assemble_system(&panels, freestream);
-    let strengths = solve_linear_system(system);
-
-    if strengths.len() != panels.len() + 1 {
-        let (cl, cm_c4, _) = approx_section_coeffs(params, alpha_deg);
-        return PanelSolution {
-            x: Vec::new(),
-            cp_upper: Vec::new(),
-            cp_lower: Vec::new(),
-            upper_coords: Vec::new(),
-            lower_coords: Vec::new(),
-            cl_cached: Some(cl),
-            cm_c4_cached: Some(cm_c4),
-        };
-    }
+/// Simple constant-strength vortex panel method with a Kutta condition.
+///
+/// Solves the loop as given, even if `PanelLuSystem::try_new` would
+/// reject it; build a system instead to reuse it across angles. If the
+/// solve fails, the solution has no Cp samples and approximate
+/// coefficients.
+pub fn compute_panel_solution<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
+    alpha_deg: f32,
+) -> PanelSolution {
+    PanelLuSystem::unchecked(airfoil, PanelMethod::ConstantSource)
+        .and_then(|system| system.try_panel_solution(alpha_deg))
+        .unwrap_or_else(|_| empty_solution(airfoil, alpha_deg))
+}
 
-    let n_panels = panels.len();
-    let gamma = strengths[n_panels];
-    let source_strengths = strengths[..n_panels].to_vec();
-    build_panel_solution_from_strengths(
-        &local,
-        alpha_deg,
-        freestream,
-        &panels,
-        &source_strengths,
-        gamma,
-    )
+/// Like `compute_panel_solution`, but validates the loop first and
+/// reports why the solve failed.
+pub fn try_compute_panel_solution<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
+    alpha_deg: f32,
+) -> Result<PanelSolution, SolveError> {
+    PanelLuSystem::try_with_method(
+        airfoil,
+        PanelMethod::ConstantSource,
+    )?
+    .try_panel_solution(alpha_deg)
 }
 
 /// Quick analytic fallback (old toy model) used for visualization when the
 /// full panel solution is too noisy for Cp plotting.
-pub fn compute_cp_approx(
-    params: &NacaParams,
+pub fn compute_cp_approx<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> PanelSolution {
-    compute_fallback_solution(params, alpha_deg)
+    compute_fallback_solution(airfoil, alpha_deg)
 }
 
 /// Explicit approximation mode (cheap, stable): does *not* run the panel solver.
-pub fn compute_approx_solution(
-    params: &NacaParams,
+pub fn compute_approx_solution<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> PanelSolution {
-    compute_fallback_solution(params, alpha_deg)
+    compute_fallback_solution(airfoil, alpha_deg)
 }
 
-struct LinearSystem {
-    matrix: Vec<f32>,
-    rhs: Vec<f32>,
-    size: usize,
-}
-
-fn build_panel_solution_from_strengths(
-    params: &NacaParams,
-    alpha_deg: f32,
-    freestream: Vec2,
+/// `(upper, lower)` wetted panels at the trailing edge.
+///
+/// Loops run TE → lower → LE → upper (`PanelLuSystem::build` reverses
+/// any that do not), so these are the last panel before the base of a
+/// blunt TE and the first one. Both have to be distinct wetted panels of
+/// non-zero length.
+fn kutta_te_panel_indices(
     panels: &[Panel],
-    sources: &[f32],
-    gamma: f32,
-) -> PanelSolution {
-    let sample_count = (params.num_points / 2).max(32);
-    let m = params.m();
-    let p = params.p();
-    let t = params.t();
-
-    let mut xs = Vec::with_capacity(sample_count);
-    let mut cp_u = Vec::with_capacity(sample_count);
-    let mut cp_l = Vec::with_capacity(sample_count);
-    let mut upper_coords = Vec::with_capacity(sample_count);
-    let mut lower_coords = Vec::with_capacity(sample_count);
-
-    for i in 0..sample_count {
-        let beta = i as f32 / (sample_count - 1) as f32;
-        let x_c = 0.5 * (1.0 - (PI * beta).cos());
-
-        let camber = camber_line(m, p, x_c);
-        let slope = camber_slope(m, p, x_c);
-        let theta = slope.atan();
-        let thickness = thickness_distribution(t, x_c);
-
-        // Upper and lower surfaces (body coords, chord = 1).
-        let upper_point = Vec2::new(
-            x_c - thickness * theta.sin(),
-            camber + thickness * theta.cos(),
-        );
-        let lower_point = Vec2::new(
-            x_c + thickness * theta.sin(),
-            camber - thickness * theta.cos(),
-        );
-
-        // Approximate outward normals from camber-line tangent. This is not
-        // exact for the thicknessed surfaces, but is stable enough for our
-        // coarse Cp sampling.
-        let tangent =
-            Vec2::new(theta.cos(), theta.sin()).normalize_or_zero();
-        let normal_upper = Vec2::new(-tangent.y, tangent.x);
-        let normal_lower = Vec2::new(tangent.y, -tangent.x);
-
-        let sample_upper =
-            upper_point + normal_upper * SURFACE_SAMPLE_EPS;
-        let sample_lower =
-            lower_point + normal_lower * SURFACE_SAMPLE_EPS;
-
-        let induced_u = induced_velocity_from_solution(
-            sample_upper,
-            panels,
-            sources,
-            gamma,
-        );
-        let induced_l = induced_velocity_from_solution(
-            sample_lower,
-            panels,
-            sources,
-            gamma,
-        );
-
-        let vel_u = freestream + induced_u;
-        let vel_l = freestream + induced_l;
-
-        let speed_u = vel_u.length();
-        let speed_l = vel_l.length();
-
-        // Cp = 1 - (V / U∞)^2, with U∞ = 1.
-        let mut cp_upper = 1.0 - speed_u * speed_u;
-        let mut cp_lower = 1.0 - speed_l * speed_l;
-
-        // Clamp extremes so the graph stays sane.
-        cp_upper = cp_upper.clamp(-3.0, 2.0);
-        cp_lower = cp_lower.clamp(-3.0, 2.0);
-
-        xs.push(x_c);
-        // Note: swapped mapping. With our current panel sign conventions this
-        // maps to the expected "upper/lower" semantics in the rest of the
-        // codebase (Cp plots, boundary layer upper/lower, CL sign).
-        cp_u.push(cp_lower);
-        cp_l.push(cp_upper);
-        upper_coords.push(lower_point);
-        lower_coords.push(upper_point);
-    }
-
-    let (cl_approx, cm_c4_approx, _) =
-        approx_section_coeffs(params, alpha_deg);
-    let cl_cached =
-        integrate_cl_from_cp(&xs, &cp_u, &cp_l).or(Some(cl_approx));
-    let cm_c4_cached = integrate_cm_c4_from_cp(&xs, &cp_u, &cp_l)
-        .or(Some(cm_c4_approx));
-    PanelSolution {
-        x: xs,
-        cp_upper: cp_u,
-        cp_lower: cp_l,
-        upper_coords,
-        lower_coords,
-        cl_cached,
-        cm_c4_cached,
-    }
-}
-
-fn kutta_te_panel_indices(panels: &[Panel]) -> (usize, usize) {
-    const MIN_TANGENT_X: f32 = 0.2;
-    const NEAR_TE_CANDIDATES: usize = 6;
-
-    let mut upper_candidates: Vec<(usize, f32, f32)> = Vec::new();
-    let mut lower_candidates: Vec<(usize, f32, f32)> = Vec::new();
-
-    for (idx, panel) in panels.iter().enumerate() {
-        if panel.length < 1e-6 {
-            continue;
-        }
-
-        // Skip near-vertical “closing” segments at the trailing edge.
-        if panel.tangent.x.abs() < MIN_TANGENT_X {
-            continue;
-        }
-
-        let entry = (idx, panel.mid.x, panel.length);
-        if panel.tangent.x > 0.0 {
-            upper_candidates.push(entry);
-        } else if panel.tangent.x < 0.0 {
-            lower_candidates.push(entry);
+) -> Result<(usize, usize), SolveError> {
+    let n = panels.len();
+    let last_wetted = match panels.last() {
+        Some(panel) if panel.te_base.is_some() => n.checked_sub(2),
+        _ => n.checked_sub(1),
+    };
+    let usable = |i: usize| {
+        panels[i].te_base.is_none() && panels[i].length > 0.0
+    };
+    match last_wetted {
+        Some(upper) if upper > 0 && usable(upper) && usable(0) => {
+            Ok((upper, 0))
         }
+        _ => Err(SolveError::KuttaPanels),
     }
-
-    let pick =
-        |mut candidates: Vec<(usize, f32, f32)>| -> Option<usize> {
-            candidates.sort_by(|a, b| b.1.total_cmp(&a.1)); // mid.x desc
-            candidates
-                .into_iter()
-                .take(NEAR_TE_CANDIDATES)
-                .max_by(|a, b| a.2.total_cmp(&b.2)) // length desc
-                .map(|(idx, _, _)| idx)
-        };
-
-    if let (Some(upper_idx), Some(lower_idx)) =
-        (pick(upper_candidates), pick(lower_candidates))
-    {
-        return (upper_idx, lower_idx);
-    }
-
-    // Fallback: use the expected ordering for our generated airfoil loop:
-    // TE(lower) → LE → TE(upper) → (closing segment to TE(lower)).
-    let n = panels.len();
-    let lower_idx = 0;
-    let upper_idx = n.saturating_sub(2);
-    (upper_idx, lower_idx)
 }
 
-fn assemble_system(panels: &[Panel], freestream: Vec2) -> LinearSystem {
+fn assemble_matrix(
+    panels: &[Panel],
+) -> Result<(Vec<f32>, usize, Vec2, Vec2), SolveError> {
     let n = panels.len();
     let size = n + 1;
     let mut matrix = vec![0.0; size * size];
-    let mut rhs = vec![0.0; size];
 
     for (i, panel_i) in panels.iter().enumerate() {
         let colloc = panel_i.mid + panel_i.normal * COLLOCATION_OFFSET;
-        rhs[i] = -freestream.dot(panel_i.normal);
 
         for (j, panel_j) in panels.iter().enumerate() {
-            let src = line_source_velocity(colloc, panel_j);
-            let vort = line_vortex_velocity(colloc, panel_j);
+            let src = panel_velocity(colloc, panel_j);
+            let vort = sheet_velocity(colloc, panel_j);
             matrix[i * size + j] = src.dot(panel_i.normal);
             matrix[i * size + n] += vort.dot(panel_i.normal);
         }
     }
 
-    let (upper_idx, lower_idx) = kutta_te_panel_indices(panels);
+    let (upper_idx, lower_idx) = kutta_te_panel_indices(panels)?;
     let upper = &panels[upper_idx];
     let lower = &panels[lower_idx];
     let upper_colloc = upper.mid + upper.normal * COLLOCATION_OFFSET;
@@ -551,61 +697,38 @@ fn assemble_system(panels: &[Panel], freestream: Vec2) -> LinearSystem {
     let upper_dir = upper.tangent;
     let lower_dir = -lower.tangent;
 
-    // Kutta: match tangential velocity on the two TE-adjacent panels.
-    rhs[n] = -freestream.dot(upper_dir) + freestream.dot(lower_dir);
-
+    // Downstream speeds on the two TE panels, per unit strength.
+    let mut te_upper = vec![0.0; size];
+    let mut te_lower = vec![0.0; size];
     for (j, panel_j) in panels.iter().enumerate() {
-        let src_upper = line_source_velocity(upper_colloc, panel_j);
-        let src_lower = line_source_velocity(lower_colloc, panel_j);
-        let vort_upper = line_vortex_velocity(upper_colloc, panel_j);
-        let vort_lower = line_vortex_velocity(lower_colloc, panel_j);
-
-        matrix[n * size + j] =
-            src_upper.dot(upper_dir) - src_lower.dot(lower_dir);
-        matrix[n * size + n] +=
-            vort_upper.dot(upper_dir) - vort_lower.dot(lower_dir);
+        te_upper[j] =
+            panel_velocity(upper_colloc, panel_j).dot(upper_dir);
+        te_lower[j] =
+            panel_velocity(lower_colloc, panel_j).dot(lower_dir);
+        te_upper[n] +=
+            sheet_velocity(upper_colloc, panel_j).dot(upper_dir);
+        te_lower[n] +=
+            sheet_velocity(lower_colloc, panel_j).dot(lower_dir);
     }
 
-    LinearSystem { matrix, rhs, size }
-}
-
-fn assemble_matrix(panels: &[Panel]) -> (Vec<f32>, usize, Vec2, Vec2) {
-    let n = panels.len();
-    let size = n + 1;
-    let mut matrix = vec![0.0; size * size];
-
-    for (i, panel_i) in panels.iter().enumerate() {
-        let colloc = panel_i.mid + panel_i.normal * COLLOCATION_OFFSET;
-
-        for (j, panel_j) in panels.iter().enumerate() {
-            let src = line_source_velocity(colloc, panel_j);
-            let vort = line_vortex_velocity(colloc, panel_j);
-            matrix[i * size + j] = src.dot(panel_i.normal);
-            matrix[i * size + n] += vort.dot(panel_i.normal);
+    // Kutta: match tangential velocity on the two TE-adjacent panels.
+    for j in 0..size {
+        matrix[n * size + j] = te_upper[j] - te_lower[j];
+    }
+
+    // A blunt base replaces its tangency condition with the source it
+    // needs to carry the mean TE speed off the surface.
+    if let Some(base) = te_base_index(panels) {
+        let k =
+            panels[base].te_base.map_or(0.0, |b| b.source_per_speed);
+        for j in 0..size {
+            matrix[base * size + j] =
+                -0.5 * k * (te_upper[j] + te_lower[j]);
         }
+        matrix[base * size + base] += 1.0;
     }
 
-    let (upper_idx, lower_idx) = kutta_te_panel_indices(panels);
-    let upper = &panels[upper_idx];
-    let lower = &panels[lower_idx];
-    let upper_colloc = upper.mid + upper.normal * COLLOCATION_OFFSET;
-    let lower_colloc = lower.mid + lower.normal * COLLOCATION_OFFSET;
-    let upper_dir = upper.tangent;
-    let lower_dir = -lower.tangent;
-
-    for (j, panel_j) in panels.iter().enumerate() {
-        let src_upper = line_source_velocity(upper_colloc, panel_j);
-        let src_lower = line_source_velocity(lower_colloc, panel_j);
-        let vort_upper = line_vortex_velocity(upper_colloc, panel_j);
-        let vort_lower = line_vortex_velocity(lower_colloc, panel_j);
-
-        matrix[n * size + j] =
-            src_upper.dot(upper_dir) - src_lower.dot(lower_dir);
-        matrix[n * size + n] +=
-            vort_upper.dot(upper_dir) - vort_lower.dot(lower_dir);
-    }
-
-    (matrix, size, upper_dir, lower_dir)
+    Ok((matrix, size, upper_dir, lower_dir))
 }
 
 fn assemble_rhs(
@@ -621,151 +744,24 @@ fn assemble_rhs(
     for (i, panel_i) in panels.iter().enumerate() {
         rhs[i] = -freestream.dot(panel_i.normal);
     }
+    if let Some(base) = te_base_index(panels) {
+        let k =
+            panels[base].te_base.map_or(0.0, |b| b.source_per_speed);
+        rhs[base] = 0.5
+            * k
+            * (freestream.dot(upper_dir) + freestream.dot(lower_dir));
+    }
 
     // Kutta: match tangential velocity on the two TE-adjacent panels.
     rhs[n] = -freestream.dot(upper_dir) + freestream.dot(lower_dir);
     rhs
 }
 
-fn solve_linear_system(system: LinearSystem) -> Vec<f32> {
-    let n = system.size;
-    let mut a = system.matrix;
-    let mut b = system.rhs;
-
-    for k in 0..n {
-        let mut pivot_row = k;
-        let mut pivot_val = a[k * n + k].abs();
-        for i in (k + 1)..n {
-            let val = a[i * n + k].abs();
-            if val > pivot_val {
-                pivot_val = val;
-                pivot_row = i;
-            }
-        }
-        if pivot_val < 1e-10 {
-            return Vec::new();
-        }
-        if pivot_row != k {
-            for col in 0..n {
-                a.swap(k * n + col, pivot_row * n + col);
-            }
-            b.swap(k, pivot_row);
-        }
-
-        let pivot = a[k * n + k];
-        if pivot.abs() < 1e-12 {
-            return Vec::new();
-        }
-        for col in 0..n {
-            a[k * n + col] /= pivot;
-        }
-        b[k] /= pivot;
-
-        for i in 0..n {
-            if i == k {
-                continue;
-            }
-            let factor = a[i * n + k];
-            if factor.abs() < 1e-9 {
-                continue;
-            }
-            for col in 0..n {
-                a[i * n + col] -= factor * a[k * n + col];
-            }
-            b[i] -= factor * b[k];
-        }
-    }
-
-    b
-}
-
-fn lu_factorize(
-    matrix: &[f32],
-    n: usize,
-) -> Option<(Vec<f32>, Vec<usize>)> {
-    let mut lu = matrix.to_vec();
-    let mut pivots: Vec<usize> = Vec::with_capacity(n);
-
-    for k in 0..n {
-        let mut pivot_row = k;
-        let mut pivot_val = lu[k * n + k].abs();
-        for i in (k + 1)..n {
-            let val = lu[i * n + k].abs();
-            if val > pivot_val {
-                pivot_val = val;
-                pivot_row = i;
-            }
-        }
-        if pivot_val < 1e-10 {
-            return None;
-        }
-        pivots.push(pivot_row);
-        if pivot_row != k {
-            for col in 0..n {
-                lu.swap(k * n + col, pivot_row * n + col);
-            }
-        }
-
-        let pivot = lu[k * n + k];
-        if pivot.abs() < 1e-12 {
-            return None;
-        }
-
-        for i in (k + 1)..n {
-            lu[i * n + k] /= pivot;
-            let lik = lu[i * n + k];
-            if lik.abs() < 1e-12 {
-                continue;
-            }
-            for j in (k + 1)..n {
-                lu[i * n + j] -= lik * lu[k * n + j];
-            }
-        }
-    }
-
-    Some((lu, pivots))
-}
-
-fn lu_solve(
-    lu: &[f32],
-    pivots: &[usize],
-    rhs: &[f32],
-    n: usize,
-) -> Option<Vec<f32>> {
-    if rhs.len() != n || pivots.len() != n || lu.len() != n * n {
-        return None;
-    }
-
-    let mut x = rhs.to_vec();
-    for (k, &pivot_row) in pivots.iter().enumerate() {
-        if pivot_row != k {
-            x.swap(k, pivot_row);
-        }
-    }
-
-    // Forward substitution: L y = P b (L has unit diagonal).
-    for i in 0..n {
-        let mut sum = x[i];
-        for j in 0..i {
-            sum -= lu[i * n + j] * x[j];
-        }
-        x[i] = sum;
-    }
-
-    // Back substitution: U x = y.
-    for i in (0..n).rev() {
-        let mut sum = x[i];
-        for j in (i + 1)..n {
-            sum -= lu[i * n + j] * x[j];
-        }
-        let diag = lu[i * n + i];
-        if diag.abs() < 1e-12 {
-            return None;
-        }
-        x[i] = sum / diag;
-    }
-
-    Some(x)
+fn te_base_index(panels: &[Panel]) -> Option<usize> {
+    panels
+        .last()
+        .filter(|panel| panel.te_base.is_some())
+        .map(|_| panels.len() - 1)
 }
 
 fn line_source_velocity(point: Vec2, panel: &Panel) -> Vec2 {
@@ -783,7 +779,7 @@ fn line_source_velocity(point: Vec2, panel: &Panel) -> Vec2 {
     let r1_sq = (x_local * x_local + y_local * y_local).max(1e-12);
     let r2_sq = (x2 * x2 + y_local * y_local).max(1e-12);
 
-    let ln_term = (r2_sq / r1_sq).ln();
+    let ln_term = (r1_sq / r2_sq).ln();
     let atan_term = y_local.atan2(x2) - y_local.atan2(x_local);
 
     let u = ln_term / (4.0 * PI);
@@ -792,6 +788,30 @@ fn line_source_velocity(point: Vec2, panel: &Panel) -> Vec2 {
     panel.tangent * u + panel.normal * v
 }
 
+/// Velocity per unit strength of a panel's own unknown: its source, plus
+/// the vortex tied to it on a blunt trailing-edge base.
+fn panel_velocity(point: Vec2, panel: &Panel) -> Vec2 {
+    let source = line_source_velocity(point, panel);
+    match panel.te_base {
+        Some(base) => {
+            source
+                + base.vortex_per_source
+                    * line_vortex_velocity(point, panel)
+        }
+        None => source,
+    }
+}
+
+/// Velocity per unit γ of the global vortex sheet on a panel. The sheet
+/// covers the wetted surface and stops at a blunt trailing-edge base.
+fn sheet_velocity(point: Vec2, panel: &Panel) -> Vec2 {
+    if panel.te_base.is_some() {
+        Vec2::ZERO
+    } else {
+        line_vortex_velocity(point, panel)
+    }
+}
+
 fn line_vortex_velocity(point: Vec2, panel: &Panel) -> Vec2 {
     let dx = point.x - panel.start.x;
     let dy = point.y - panel.start.y;
@@ -807,7 +827,7 @@ fn line_vortex_velocity(point: Vec2, panel: &Panel) -> Vec2 {
     let r1_sq = (x_local * x_local + y_local * y_local).max(1e-12);
     let r2_sq = (x2 * x2 + y_local * y_local).max(1e-12);
 
-    let ln_term = (r2_sq / r1_sq).ln();
+    let ln_term = (r1_sq / r2_sq).ln();
     let atan_term = y_local.atan2(x2) - y_local.atan2(x_local);
 
     let u = -atan_term / (2.0 * PI);
@@ -824,14 +844,14 @@ fn induced_velocity_from_solution(
 ) -> Vec2 {
     let mut vel = Vec2::ZERO;
     for (panel, &sigma) in panels.iter().zip(sources.iter()) {
-        let infl = line_source_velocity(point, panel);
+        let infl = panel_velocity(point, panel);
         vel += infl * sigma;
     }
 
     if gamma.abs() > 0.0 {
         let mut vort = Vec2::ZERO;
         for panel in panels {
-            vort += line_vortex_velocity(point, panel);
+            vort += sheet_velocity(point, panel);
         }
         vel += vort * gamma;
     }
@@ -839,17 +859,17 @@ fn induced_velocity_from_solution(
     vel
 }
 
-fn zero_lift_alpha(params: &NacaParams) -> f32 {
-    let alpha0_deg = -50.0 * params.m();
-    alpha0_deg.to_radians()
-}
-
-fn compute_fallback_solution(
-    params: &NacaParams,
+fn compute_fallback_solution<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
     alpha_deg: f32,
 ) -> PanelSolution {
-    let n = (params.num_points / 2).max(32);
-    let alpha_rad = alpha_deg.to_radians() - zero_lift_alpha(params);
+    let points = airfoil.panel_points();
+    let stations =
+        sample_stations(airfoil, &points, sample_count(points.len()));
+    let thin = ThinAirfoil::from_airfoil(airfoil);
+    let n = stations.len();
+    let alpha_rad =
+        (alpha_deg - thin.zero_lift_alpha_deg()).to_radians();
 
     let mut xs = Vec::with_capacity(n);
     let mut cp_u = Vec::with_capacity(n);
@@ -857,58 +877,37 @@ fn compute_fallback_solution(
     let mut upper_coords = Vec::with_capacity(n);
     let mut lower_coords = Vec::with_capacity(n);
 
-    for i in 0..n {
-        // Cosine spacing along the chord: better LE resolution.
-        let beta = i as f32 / (n - 1) as f32;
-        let x_c = 0.5 * (1.0 - (PI * beta).cos());
-
-        // Standard NACA thickness distribution.
-        let y_t = thickness_distribution(params.t(), x_c);
-
-        // Camber line and slope.
-        let y_c = camber_line(params.m(), params.p(), x_c);
-        let dyc_dx = camber_slope(params.m(), params.p(), x_c);
-
-        let theta = dyc_dx.atan();
-
-        // Upper and lower surfaces (body coords, chord = 1).
-        let x_u = x_c - y_t * theta.sin();
-        let y_u = y_c + y_t * theta.cos();
-
-        let x_l = x_c + y_t * theta.sin();
-        let y_l = y_c - y_t * theta.cos();
-
-        let v_u = surface_velocity(Vec2::new(x_u, y_u), alpha_rad);
-        let v_l = surface_velocity(Vec2::new(x_l, y_l), alpha_rad);
-
-        let speed_u = v_u.length();
-        let speed_l = v_l.length();
-
-        // Cp = 1 - (V / U∞)^2, with U∞ = 1.
-        let mut cp_upper = 1.0 - speed_u * speed_u;
-        let mut cp_lower = 1.0 - speed_l * speed_l;
-
-        // Clamp extremes so the graph stays sane.
-        cp_upper = cp_upper.clamp(-3.0, 2.0);
-        cp_lower = cp_lower.clamp(-3.0, 2.0);
+    // Cp = 1 - (V / U∞)^2, with U∞ = 1, clamped so the graph stays sane.
+    let cp = |p: Vec2| {
+        let speed = surface_velocity(p, alpha_rad).length();
+        (1.0 - speed * speed).clamp(-3.0, 2.0)
+    };
 
-        xs.push(x_c);
-        cp_u.push(cp_lower);
-        cp_l.push(cp_upper);
-        upper_coords.push(Vec2::new(x_l, y_l));
-        lower_coords.push(Vec2::new(x_u, y_u));
+    // Stations are cosine-spaced along the chord: better LE resolution.
+    for station in &stations {
+        xs.push(station.x);
+        cp_u.push(cp(station.upper));
+        cp_l.push(cp(station.lower));
+        upper_coords.push(station.upper);
+        lower_coords.push(station.lower);
     }
 
-    let (cl_cached, cm_c4_cached, _) =
-        approx_section_coeffs(params, alpha_deg);
     PanelSolution {
         x: xs,
         cp_upper: cp_u,
         cp_lower: cp_l,
         upper_coords,
         lower_coords,
-        cl_cached: Some(cl_cached),
-        cm_c4_cached: Some(cm_c4_cached),
+        surface_points: Vec::new(),
+        arc_length: Vec::new(),
+        tangential_velocity: Vec::new(),
+        cp: Vec::new(),
+        alpha_deg,
+        quarter_chord: Vec2::new(0.25, 0.0),
+        chord: 1.0,
+        coefficient_source: CoefficientSource::Approximate,
+        cl_cached: Some(thin.cl(alpha_deg)),
+        cm_c4_cached: Some(thin.cm_c4()),
     }
 }
 
@@ -922,11 +921,11 @@ fn surface_velocity(p: Vec2, alpha_rad: f32) -> Vec2 {
     let r2 = r.length_squared().max(1e-4);
     let r_len = r2.sqrt();
 
-    // Circulation ∝ α
+    // Circulation ∝ α, clockwise for positive lift.
     let gamma = 4.0 * PI * alpha_rad;
 
     let tangential_dir = if r_len > 0.0 {
-        Vec2::new(-r.y, r.x) / r_len
+        Vec2::new(r.y, -r.x) / r_len
     } else {
         Vec2::ZERO
     };
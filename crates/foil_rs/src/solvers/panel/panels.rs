@@ -1,4 +1,5 @@
-use crate::math::Vec2;
+use crate::airfoil::surface_run;
+use crate::math::{Vec2, signed_area};
 
 pub(crate) struct Panel {
     pub(crate) start: Vec2,
@@ -6,11 +7,32 @@ pub(crate) struct Panel {
     pub(crate) normal: Vec2,
     pub(crate) tangent: Vec2,
     pub(crate) length: f32,
+    /// Set on the closing panel across a blunt trailing edge.
+    pub(crate) te_base: Option<TeBase>,
 }
 
+/// The base panel of a blunt trailing edge (XFoil's TE panel).
+///
+/// The global vortex sheet stops at the base. Instead the flow leaving the
+/// TE along its bisector `s` is carried across the base by a source and
+/// a vortex, tied to the TE surface speed by the Kutta condition.
+#[derive(Clone, Copy)]
+pub(crate) struct TeBase {
+    /// Source strength per unit TE surface speed, `s · normal`.
+    pub(crate) source_per_speed: f32,
+    /// Vortex strength per unit source strength, `-(s · t) / (s · n)`.
+    pub(crate) vortex_per_source: f32,
+}
+
+/// Smallest `s · normal` used for a base, so a base nearly aligned with
+/// the flow does not take an unbounded vortex.
+const MIN_BASE_NORMAL_FLOW: f32 = 0.1;
+
 pub(crate) fn build_panels(points: &[Vec2]) -> Vec<Panel> {
     let mut panels = Vec::with_capacity(points.len() - 1);
-    let area = polygon_signed_area(points);
+    let area = signed_area(points);
+    let (run, _) = surface_run(points);
+    let blunt = run.len() < points.len();
 
     for i in 0..points.len() - 1 {
         let p0 = points[i];
@@ -30,18 +52,25 @@ pub(crate) fn build_panels(points: &[Vec2]) -> Vec<Panel> {
             normal,
             tangent,
             length: len,
+            te_base: None,
         });
     }
 
-    panels
-}
-
-fn polygon_signed_area(points: &[Vec2]) -> f32 {
-    let mut area = 0.0;
-    for i in 0..points.len() - 1 {
-        let p0 = points[i];
-        let p1 = points[i + 1];
-        area += p0.x * p1.y - p1.x * p0.y;
+    if blunt && panels.len() >= 3 {
+        let n = panels.len();
+        // Downstream directions along the last wetted panels.
+        let upper_dir = panels[n - 2].tangent;
+        let lower_dir = -panels[0].tangent;
+        let bisector = (upper_dir + lower_dir).normalize_or(Vec2::X);
+        let base = &mut panels[n - 1];
+        let normal_flow =
+            bisector.dot(base.normal).abs().max(MIN_BASE_NORMAL_FLOW);
+        base.te_base = Some(TeBase {
+            source_per_speed: normal_flow,
+            vortex_per_source: -bisector.dot(base.tangent)
+                / normal_flow,
+        });
     }
-    0.5 * area
+
+    panels
 }
@@ -0,0 +1,191 @@
+use std::f32::consts::PI;
+
+use crate::airfoil::surface_run;
+use crate::math::{Vec2, signed_area};
+
+use super::{
+    CoefficientSource, PanelMethod, PanelSolution, sample_count,
+};
+
+/// Pressure force and moment coefficients of a section.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SectionForces {
+    /// Lift, normal to the freestream.
+    pub cl: f32,
+    /// Pressure drag, along the freestream.
+    pub cd_pressure: f32,
+    /// Pitching moment about the reference point, nose-up positive.
+    pub cm: f32,
+}
+
+/// Solution from a solver's own surface points, listed in loop order.
+///
+/// Cp = 1 - u² (U∞ = 1) at each point. The per-surface arrays are
+/// interpolated along the points at cosine-spaced chord stations, with
+/// x/c measured from the loop's LE (minimum x) to its TE midpoint.
+/// Coefficients come from Cp integrated round the loop and are based on
+/// that LE → TE chord, with the moment about the quarter-chord point.
+pub(crate) fn surface_solution(
+    loop_points: &[Vec2],
+    points: Vec<Vec2>,
+    arc_length: Vec<f32>,
+    tangential_velocity: Vec<f32>,
+    alpha_deg: f32,
+    method: PanelMethod,
+) -> PanelSolution {
+    let cp: Vec<f32> =
+        tangential_velocity.iter().map(|u| 1.0 - u * u).collect();
+
+    let (run, _) = surface_run(loop_points);
+    let nose = run
+        .iter()
+        .copied()
+        .min_by(|a, b| a.x.total_cmp(&b.x))
+        .unwrap_or(Vec2::ZERO);
+    let tail = 0.5 * (run[0] + run[run.len() - 1]);
+    let (x_le, chord) = (nose.x, tail.x - nose.x);
+    let le = points
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+        .map_or(0, |(i, _)| i);
+    // Both surfaces from the LE aft; solver ordering (clockwise) puts the
+    // upper one second.
+    let samples: Vec<(Vec2, f32)> =
+        points.iter().copied().zip(cp.iter().copied()).collect();
+    let first: Vec<(Vec2, f32)> =
+        samples[..=le].iter().rev().copied().collect();
+    let second = &samples[le..];
+    let (upper, lower) = if signed_area(loop_points) <= 0.0 {
+        (second, first.as_slice())
+    } else {
+        (first.as_slice(), second)
+    };
+
+    let count = sample_count(loop_points.len());
+    let x: Vec<f32> = (0..count)
+        .map(|i| {
+            let beta = i as f32 / (count - 1) as f32;
+            0.5 * (1.0 - (PI * beta).cos())
+        })
+        .collect();
+    let stations = x.iter().map(|x_c| x_le + x_c * chord);
+    let (upper_coords, cp_upper): (Vec<Vec2>, Vec<f32>) =
+        along_surface(upper, stations.clone()).into_iter().unzip();
+    let (lower_coords, cp_lower): (Vec<Vec2>, Vec<f32>) =
+        along_surface(lower, stations).into_iter().unzip();
+
+    let quarter_chord = nose.lerp(tail, 0.25);
+    let forces = integrate_forces(
+        &points,
+        &cp,
+        alpha_deg.to_radians(),
+        quarter_chord,
+        chord,
+    );
+    PanelSolution {
+        x,
+        cp_upper,
+        cp_lower,
+        upper_coords,
+        lower_coords,
+        surface_points: points,
+        arc_length,
+        tangential_velocity,
+        cp,
+        alpha_deg,
+        quarter_chord,
+        chord,
+        coefficient_source: CoefficientSource::SurfacePressure(method),
+        cl_cached: forces.map(|f| f.cl),
+        cm_c4_cached: forces.map(|f| f.cm),
+    }
+}
+
+/// Point and Cp along a surface listed from the LE aft, at chord
+/// positions `xs` in ascending order. Cp is clamped so the graph stays
+/// sane. Walks both lists once.
+fn along_surface(
+    surface: &[(Vec2, f32)],
+    xs: impl Iterator<Item = f32>,
+) -> Vec<(Vec2, f32)> {
+    let last = surface.len().saturating_sub(1);
+    let mut i = 0;
+    xs.map(|x| {
+        while i + 1 < last && x > surface[i + 1].0.x {
+            i += 1;
+        }
+        while i > 0 && x < surface[i].0.x {
+            i -= 1;
+        }
+        let (a, b) = (surface[i], surface[(i + 1).min(last)]);
+        let t = if b.0.x > a.0.x {
+            ((x - a.0.x) / (b.0.x - a.0.x)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let y = a.0.y + t * (b.0.y - a.0.y);
+        let cp = a.1 + t * (b.1 - a.1);
+        (Vec2::new(x, y), cp.clamp(-3.0, 2.0))
+    })
+    .collect()
+}
+
+/// Forces from Cp varying linearly between consecutive points of a
+/// closed loop, in wind axes like XFoil's `CLCALC`, per unit `chord`
+/// (the moment per unit chord squared).
+pub(crate) fn integrate_forces(
+    points: &[Vec2],
+    cp: &[f32],
+    alpha_rad: f32,
+    reference: Vec2,
+    chord: f32,
+) -> Option<SectionForces> {
+    let n = points.len();
+    if n < 3 || cp.len() != n {
+        return None;
+    }
+    let (sa, ca) = alpha_rad.sin_cos();
+    let wind =
+        |d: Vec2| Vec2::new(d.x * ca + d.y * sa, d.y * ca - d.x * sa);
+    let (mut cl, mut cd, mut cm) = (0.0, 0.0, 0.0);
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let d = wind(points[j] - points[i]);
+        let a = wind(0.5 * (points[i] + points[j]) - reference);
+        let (mean, delta) = (0.5 * (cp[i] + cp[j]), cp[j] - cp[i]);
+        cl -= d.x * mean;
+        cd += d.y * mean;
+        cm += d.x * (mean * a.x + delta * d.x / 12.0)
+            + d.y * (mean * a.y + delta * d.y / 12.0);
+    }
+    // Written for clockwise loops, where the outward normal is to the
+    // left of the direction of travel.
+    let sign = if signed_area(points) > 0.0 { -1.0 } else { 1.0 };
+    Some(SectionForces {
+        cl: sign * cl / chord,
+        cd_pressure: sign * cd / chord,
+        cm: sign * cm / (chord * chord),
+    })
+}
+
+/// Clockwise circulation round a closed loop from the surface speeds,
+/// positive along the loop, at its points.
+pub(crate) fn circulation(
+    points: &[Vec2],
+    speeds: &[f32],
+) -> Option<f32> {
+    let n = points.len();
+    if n < 3 || speeds.len() != n {
+        return None;
+    }
+    let gamma: f32 = (0..n)
+        .map(|i| {
+            let j = (i + 1) % n;
+            0.5 * (speeds[i] + speeds[j])
+                * points[i].distance(points[j])
+        })
+        .sum();
+    let sign = if signed_area(points) > 0.0 { -1.0 } else { 1.0 };
+    Some(sign * gamma)
+}
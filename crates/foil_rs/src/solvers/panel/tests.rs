@@ -27,6 +27,22 @@ fn cl_scales_with_alpha() {
     );
 }
 
+#[test]
+fn panel_influence_signs_give_reference_lift() {
+    // With ln(r2/r1) in the tangential influence the circulation
+    // collapsed; pin the inviscid 2412 lift so the sign cannot regress.
+    let (cl_ref, _, _) =
+        crate::state::reference_coeffs(&NacaParams::default(), 0.0)
+            .unwrap();
+    let cl0 = solve_cl(0.0);
+    assert!((cl0 - cl_ref).abs() < 0.01, "CL(0) {cl0} vs {cl_ref}");
+
+    // Thickness lifts the slope to about 2π(1 + 0.77 t/c) per radian.
+    let slope = (solve_cl(4.0) - solve_cl(-4.0)) / 8.0_f32.to_radians();
+    let expected = 2.0 * PI * (1.0 + 0.77 * 0.12);
+    assert!((slope - expected).abs() < 0.1, "slope {slope}");
+}
+
 #[test]
 fn cl_snapshot_alpha0_print() {
     let params = NacaParams::default();
@@ -44,9 +60,7 @@ fn pg_scaling_increases_induced_velocity_with_mach() {
 
     let system =
         PanelLuSystem::new(&params).expect("panel system should build");
-    let flow = system
-        .solve_flow(alpha_deg)
-        .expect("panel flow should solve");
+    let flow = system.solve_flow(alpha_deg);
     let p = Vec2::new(0.3, 0.05);
 
     let v0 = flow.velocity_body_pg(p, 0.0);
@@ -86,3 +100,544 @@ fn odd_even_panel_counts_should_match() {
         diff
     );
 }
+
+#[test]
+fn coordinate_airfoil_runs_through_panel_solver() {
+    // A coordinate-only copy of a symmetric section samples the same
+    // surface points as the analytic one.
+    let params = NacaParams::from_naca4("0012").unwrap();
+    let airfoil = crate::airfoil::Airfoil::from_shape(&params);
+    let cl_naca = compute_panel_solution(&params, 4.0)
+        .cl()
+        .unwrap_or(f32::NAN);
+    let cl_coords = compute_panel_solution(&airfoil, 4.0)
+        .cl()
+        .unwrap_or(f32::NAN);
+    assert!(
+        (cl_naca - cl_coords).abs() < 0.02,
+        "coordinate loop diverged from analytic NACA: {} vs {}",
+        cl_naca,
+        cl_coords
+    );
+
+    let cambered = crate::airfoil::Airfoil::from_shape(
+        &NacaParams::from_naca4("2412").unwrap(),
+    );
+    let sys = PanelLuSystem::new(&cambered).expect("panel system");
+    let cl = sys.panel_solution(4.0).cl().unwrap_or(0.0);
+    assert!(cl > 0.3, "expected positive lift, got {cl}");
+}
+
+#[test]
+fn repaneled_coarse_loop_matches_dense_loop() {
+    // A sparse coordinate set (like many .dat files) repaneled by
+    // curvature should behave like the dense analytic section.
+    let coarse = NacaParams {
+        num_points: 24,
+        ..NacaParams::from_naca4("0012").unwrap()
+    };
+    let coarse = crate::airfoil::Airfoil::from_shape(&coarse);
+    let repaneled = crate::airfoil::Repaneled::new(
+        coarse,
+        crate::airfoil::PanelingParams::default(),
+    );
+    let sys = PanelLuSystem::new(&repaneled).expect("panel system");
+    let cl = sys.panel_solution(4.0).cl().unwrap_or(f32::NAN);
+    let cl_dense = solve_cl_for("0012", 4.0);
+    assert!(
+        (cl - cl_dense).abs() < 0.05,
+        "repaneled loop diverged: {cl} vs {cl_dense}"
+    );
+}
+
+#[test]
+fn flap_deflection_adds_lift() {
+    let flapped = crate::airfoil::Flapped::new(
+        NacaParams::from_naca4("0012").unwrap(),
+        crate::airfoil::FlapParams {
+            deflection_deg: 10.0,
+            ..Default::default()
+        },
+    );
+    let sys = PanelLuSystem::new(&flapped).expect("panel system");
+    let cl = sys.panel_solution(0.0).cl().unwrap_or(f32::NAN);
+    // Thin-airfoil theory for a 25% flap: dCL/dδ = 2(π - θf + sin θf)
+    // with cos θf = 1 - 2 x_hinge = -0.5, about 0.067 per degree.
+    assert!(cl > 0.6 && cl < 0.8, "CL {cl} at 10° flap");
+}
+
+#[test]
+fn thick_trailing_edge_solves_like_the_thin_one() {
+    let params = NacaParams::from_naca4("0012").unwrap();
+    let blunt = crate::airfoil::Airfoil::new(
+        "0012 TGAP 2%",
+        crate::airfoil::set_te_gap(&params.panel_points(), 0.02, 0.8),
+    );
+    let sys = PanelLuSystem::new(&blunt).expect("panel system");
+    let sol = sys.panel_solution(4.0);
+    let cl = sol.cl().unwrap_or(f32::NAN);
+    let cl_thin_te = solve_cl_for("0012", 4.0);
+    assert!(
+        (cl - cl_thin_te).abs() < 0.03,
+        "blunt TE CL {cl} vs {cl_thin_te}"
+    );
+    // The flow recovers towards the base on both surfaces instead of
+    // racing round its corners.
+    let te = sol.x.len() - 2;
+    let (cp_u, cp_l) = (sol.cp_upper[te], sol.cp_lower[te]);
+    assert!(cp_u > 0.3 && cp_l > 0.3, "TE Cp {cp_u} / {cp_l}");
+    assert!((cp_u - cp_l).abs() < 0.1, "TE Cp {cp_u} / {cp_l}");
+
+    let sym = sys.panel_solution(0.0).cl().unwrap_or(f32::NAN);
+    assert!(sym.abs() < 1e-3, "symmetric blunt section CL {sym}");
+}
+
+#[test]
+fn kutta_panels_follow_the_loop_ends() {
+    let params = NacaParams::from_naca4("2412").unwrap();
+    let sharp = crate::airfoil::Airfoil::new(
+        "2412 sharp",
+        crate::airfoil::set_te_gap(&params.panel_points(), 0.0, 0.8),
+    );
+    let panels = build_panels(&sharp.panel_points());
+    assert!(panels.iter().all(|p| p.te_base.is_none()));
+    assert_eq!(
+        kutta_te_panel_indices(&panels),
+        Ok((panels.len() - 1, 0))
+    );
+
+    // A slanted base, as on a section cut off at different stations.
+    let points = params.panel_points();
+    let le = points.len() / 2;
+    let mut cut: Vec<Vec2> = points[..le]
+        .iter()
+        .copied()
+        .filter(|p| p.x < 0.92)
+        .collect();
+    cut.extend(points[le..].iter().copied().filter(|p| p.x < 0.9));
+    cut.push(cut[0]);
+    let panels = build_panels(&cut);
+    let base = panels.last().and_then(|p| p.te_base).expect("base");
+    assert!(base.vortex_per_source.abs() > 0.1);
+    assert_eq!(
+        kutta_te_panel_indices(&panels),
+        Ok((panels.len() - 2, 0))
+    );
+
+    let cut = crate::airfoil::Airfoil::new("2412 cut", cut);
+    let sys = PanelLuSystem::try_new(&cut).expect("panel system");
+    let cl = sys.panel_solution(4.0).cl().unwrap_or(f32::NAN);
+    assert!(cl > 0.5 && cl < 1.0, "cut section CL {cl}");
+}
+
+#[test]
+fn basis_solutions_superpose_to_any_alpha() {
+    let params = NacaParams::from_naca4("2412").unwrap();
+    let sys = PanelLuSystem::new(&params).expect("panel system");
+    let Solver::ConstantSource(source) = &sys.solver else {
+        panic!("expected the source method");
+    };
+    let (matrix, size, upper_dir, lower_dir) =
+        assemble_matrix(&source.panels).expect("matrix");
+    for alpha_deg in [-7.0, 3.0, 12.0] {
+        let freestream = freestream(alpha_deg);
+        let rhs = assemble_rhs(
+            &source.panels,
+            freestream,
+            upper_dir,
+            lower_dir,
+        );
+        let (sources, gamma) = source.strengths(freestream);
+        for (i, b) in rhs.iter().enumerate() {
+            let row = &matrix[i * size..(i + 1) * size];
+            let lhs: f32 = row
+                .iter()
+                .zip(&sources)
+                .map(|(a, s)| a * s)
+                .sum::<f32>()
+                + row[size - 1] * gamma;
+            assert!((lhs - b).abs() < 1e-4, "α {alpha_deg} row {i}");
+        }
+    }
+}
+
+#[test]
+fn linear_vorticity_matches_xfoil_reference() {
+    let params = NacaParams::from_naca4("2412").unwrap();
+    let (cl_ref, cm_ref, _) =
+        crate::state::reference_coeffs(&params, 0.0).unwrap();
+    let sys = PanelLuSystem::with_method(
+        &params,
+        PanelMethod::LinearVorticity,
+    )
+    .expect("panel system");
+    assert_eq!(sys.method(), PanelMethod::LinearVorticity);
+    let sol = sys.panel_solution(0.0);
+    let (cl, cm) = (sol.cl().unwrap(), sol.cm_c4().unwrap());
+    assert!((cl - cl_ref).abs() < 0.008, "CL {cl} vs {cl_ref}");
+    assert!((cm - cm_ref).abs() < 0.002, "CM {cm} vs {cm_ref}");
+}
+
+#[test]
+fn linear_vorticity_converges_with_smooth_le_cp() {
+    let solve = |num_points| {
+        let params = NacaParams {
+            num_points,
+            ..NacaParams::from_naca4("0012").unwrap()
+        };
+        let sharp = crate::airfoil::Airfoil::new(
+            "0012 sharp",
+            crate::airfoil::set_te_gap(
+                &params.panel_points(),
+                0.0,
+                0.8,
+            ),
+        );
+        let sys = PanelLuSystem::with_method(
+            &sharp,
+            PanelMethod::LinearVorticity,
+        )
+        .expect("panel system");
+        // The body is a closed streamline with still fluid inside.
+        let flow = sys.solve_flow(4.0);
+        let inside = flow.velocity_body_pg(Vec2::new(0.3, 0.0), 0.0);
+        assert!(inside.length() < 1e-3, "inside {inside}");
+        sys.panel_solution(4.0)
+    };
+    let coarse = solve(60).cl().unwrap();
+    let fine = solve(240);
+    let cl = fine.cl().unwrap();
+    assert!((cl - coarse).abs() < 2e-3, "{coarse} vs {cl}");
+    assert!(cl > 0.478 && cl < 0.487, "CL {cl}");
+
+    // One suction peak near the nose, no wiggles either side of it.
+    let front = fine.x.iter().take_while(|&&x| x < 0.3).count();
+    let slopes: Vec<f32> = fine.cp_upper[..front]
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .collect();
+    let turns = slopes
+        .windows(2)
+        .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+        .count();
+    assert_eq!(turns, 1, "upper Cp {:?}", &fine.cp_upper[..front]);
+}
+
+#[test]
+fn surface_quantities_come_from_the_solver_loop() {
+    // A coordinate loop moved off the unit chord: nothing analytic to
+    // resample, so everything has to come from the panels themselves.
+    let points: Vec<Vec2> = NacaParams::from_naca4("2412")
+        .unwrap()
+        .panel_points()
+        .iter()
+        .map(|p| *p + Vec2::new(0.5, 0.1))
+        .collect();
+    let shifted = crate::airfoil::Airfoil::new("shifted", points);
+    for method in
+        [PanelMethod::ConstantSource, PanelMethod::LinearVorticity]
+    {
+        let sys = PanelLuSystem::with_method(&shifted, method)
+            .expect("panel system");
+        let sol = sys.panel_solution(4.0);
+        let n = sol.surface_points.len();
+        assert!(n > 100, "{method:?}: {n} surface points");
+        assert_eq!(sol.arc_length.len(), n);
+        assert_eq!(sol.tangential_velocity.len(), n);
+        assert_eq!(sol.cp.len(), n);
+        assert!(sol.arc_length.windows(2).all(|w| w[1] > w[0]));
+        for (u, cp) in sol.tangential_velocity.iter().zip(&sol.cp) {
+            assert!((cp - (1.0 - u * u)).abs() < 1e-5);
+        }
+        let stagnation =
+            sol.cp.iter().copied().fold(f32::MIN, f32::max);
+        assert!(stagnation > 0.95, "{method:?}: max Cp {stagnation}");
+
+        // Chord stations span the shifted section.
+        assert!(sol.x[0].abs() < 1e-6);
+        assert!((sol.x[sol.x.len() - 1] - 1.0).abs() < 1e-6);
+        assert!((sol.upper_coords[0].x - 0.5).abs() < 1e-4);
+        let mid = sol.x.len() / 2;
+        assert!(sol.upper_coords[mid].y > sol.lower_coords[mid].y);
+        assert!(sol.cp_upper[mid] < sol.cp_lower[mid]);
+        let cl = sol.cl().unwrap();
+        assert!(cl > 0.6 && cl < 0.8, "{method:?}: CL {cl}");
+    }
+}
+
+#[test]
+fn reversed_loops_solve_like_forward_ones() {
+    let params = NacaParams::from_naca4("2412").unwrap();
+    let mut points = params.panel_points();
+    points.reverse();
+    let reversed = crate::airfoil::Airfoil::new("reversed", points);
+    for method in
+        [PanelMethod::ConstantSource, PanelMethod::LinearVorticity]
+    {
+        let solve = |airfoil: &dyn AirfoilShape| {
+            let sys = PanelLuSystem::with_method(airfoil, method)
+                .expect("panel system");
+            let sol = sys.panel_solution(4.0);
+            (sol.cl().unwrap(), sol.cm_c4().unwrap(), sys)
+        };
+        let (cl, cm, _) = solve(&params);
+        let (cl_rev, cm_rev, sys) = solve(&reversed);
+        assert!(sys.warnings().contains(
+            &crate::airfoil::GeometryWarning::ReversedOrdering
+        ));
+        assert!(
+            (cl - cl_rev).abs() < 1e-4,
+            "{method:?}: {cl} {cl_rev}"
+        );
+        assert!(
+            (cm - cm_rev).abs() < 1e-4,
+            "{method:?}: {cm} {cm_rev}"
+        );
+    }
+}
+
+#[test]
+fn coefficients_are_per_unit_chord() {
+    let params = NacaParams::from_naca4("2412").unwrap();
+    let points: Vec<Vec2> =
+        params.panel_points().iter().map(|p| 2.0 * *p).collect();
+    let scaled = crate::airfoil::Airfoil::new("scaled", points);
+    for method in
+        [PanelMethod::ConstantSource, PanelMethod::LinearVorticity]
+    {
+        let solve = |airfoil: &dyn AirfoilShape| {
+            PanelLuSystem::with_method(airfoil, method)
+                .expect("panel system")
+                .panel_solution(4.0)
+        };
+        let (unit, twice) = (solve(&params), solve(&scaled));
+        let pairs = [
+            ("CL", unit.cl(), twice.cl()),
+            ("CM c/4", unit.cm_c4(), twice.cm_c4()),
+            (
+                "KJ CL",
+                unit.kutta_joukowski_cl(),
+                twice.kutta_joukowski_cl(),
+            ),
+            (
+                "CM LE",
+                unit.cm_about(Vec2::ZERO),
+                twice.cm_about(Vec2::ZERO),
+            ),
+        ];
+        // Unscaled, the doubled loop would give twice the CL and four
+        // times the CM; what is left comes from the fixed collocation
+        // offset.
+        for (name, a, b) in pairs {
+            let (a, b) = (a.unwrap(), b.unwrap());
+            assert!(
+                (a - b).abs() < 5e-3,
+                "{method:?} {name}: {a} vs {b}"
+            );
+        }
+        let cl = twice.cl().unwrap();
+        assert!((cl - 0.736).abs() < 0.01, "{method:?}: CL {cl}");
+    }
+}
+
+#[test]
+fn pressure_forces_agree_with_circulation() {
+    for code in ["0012", "2412"] {
+        let solutions = solutions_for(code, 4.0);
+        for (name, sol) in &solutions[..2] {
+            let forces = sol
+                .surface_forces(Vec2::new(0.25, 0.0))
+                .expect("surface forces");
+            assert_eq!(Some(forces.cl), sol.cl(), "{code} {name}");
+            let kj = sol.kutta_joukowski_cl().unwrap();
+            assert!(
+                (kj - forces.cl).abs() < 0.02 * forces.cl,
+                "{code} {name}: pressure {} vs circulation {kj}",
+                forces.cl
+            );
+            // Closed inviscid flow: no pressure drag.
+            assert!(forces.cd_pressure.abs() < 0.005, "{code} {name}");
+
+            // About the LE, lift at the quarter chord adds a nose-down
+            // CL / 4.
+            let le = sol.surface_forces(Vec2::ZERO).unwrap();
+            let shifted = forces.cm - 0.25 * forces.cl;
+            assert!((le.cm - shifted).abs() < 0.005, "{code} {name}");
+            assert!(matches!(
+                sol.coefficient_source,
+                CoefficientSource::SurfacePressure(_)
+            ));
+        }
+        let (_, approx) = &solutions[2];
+        assert_eq!(
+            approx.coefficient_source,
+            CoefficientSource::Approximate
+        );
+        assert!(approx.surface_forces(Vec2::ZERO).is_none());
+    }
+}
+
+/// Solutions from each solver path for one section and α.
+fn solutions_for(
+    code: &str,
+    alpha_deg: f32,
+) -> Vec<(&'static str, PanelSolution)> {
+    let params = NacaParams::from_naca4(code).unwrap();
+    let linear = PanelLuSystem::with_method(
+        &params,
+        PanelMethod::LinearVorticity,
+    )
+    .expect("panel system");
+    vec![
+        ("source", compute_panel_solution(&params, alpha_deg)),
+        ("linear", linear.panel_solution(alpha_deg)),
+        ("approx", compute_approx_solution(&params, alpha_deg)),
+    ]
+}
+
+/// Mean Cp over the front half of a surface.
+fn front_cp(sol: &PanelSolution, cp: &[f32]) -> f32 {
+    let front = sol.x.iter().take_while(|&&x| x < 0.5).count();
+    cp[..front].iter().sum::<f32>() / front as f32
+}
+
+#[test]
+fn upper_surface_is_the_suction_side_at_positive_lift() {
+    for code in ["0012", "2412"] {
+        for alpha in [-4.0, 4.0] {
+            for (name, sol) in solutions_for(code, alpha) {
+                let mid = sol.x.len() / 2;
+                assert!(
+                    sol.upper_coords[mid].y > sol.lower_coords[mid].y,
+                    "{code} {name}: upper surface below lower"
+                );
+                let cl = sol.cl().unwrap();
+                assert_eq!(
+                    cl > 0.0,
+                    alpha > 0.0,
+                    "{code} {name} α={alpha}: CL {cl}"
+                );
+                let (upper, lower) = (
+                    front_cp(&sol, &sol.cp_upper),
+                    front_cp(&sol, &sol.cp_lower),
+                );
+                assert_eq!(
+                    upper < lower,
+                    alpha > 0.0,
+                    "{code} {name} α={alpha}: upper {upper}, lower {lower}"
+                );
+            }
+        }
+    }
+    assert!(cp_plot_y(-1.0) > cp_plot_y(0.0));
+}
+
+#[test]
+fn symmetric_sections_mirror_and_camber_pitches_nose_down() {
+    let positive = solutions_for("0012", 4.0);
+    let negative = solutions_for("0012", -4.0);
+    for ((name, pos), (_, neg)) in positive.iter().zip(&negative) {
+        let mid = pos.x.len() / 4;
+        assert!(
+            (pos.cp_upper[mid] - neg.cp_lower[mid]).abs() < 0.02,
+            "{name}: {} vs {}",
+            pos.cp_upper[mid],
+            neg.cp_lower[mid]
+        );
+        let (cl_pos, cl_neg) = (pos.cl().unwrap(), neg.cl().unwrap());
+        assert!(
+            (cl_pos + cl_neg).abs() < 0.01,
+            "{name}: {cl_pos} vs {cl_neg}"
+        );
+        assert!(pos.cm_c4().unwrap().abs() < 0.01, "{name}: CM");
+    }
+
+    // Aft camber loading pitches the nose down at zero lift and beyond.
+    for alpha in [-4.0, 0.0, 4.0] {
+        for (name, sol) in solutions_for("2412", alpha) {
+            let cm = sol.cm_c4().unwrap();
+            assert!(cm < -0.03, "2412 {name} α={alpha}: CM {cm}");
+        }
+    }
+}
+
+fn solve_cl_for(code: &str, alpha_deg: f32) -> f32 {
+    let params = NacaParams::from_naca4(code).unwrap();
+    compute_panel_solution(&params, alpha_deg)
+        .cl()
+        .unwrap_or(f32::NAN)
+}
+
+#[test]
+fn invalid_loops_are_rejected_with_a_reason() {
+    let params = NacaParams::default();
+    let mut points = params.panel_points();
+    points.pop();
+    let open = crate::airfoil::Airfoil::new("open", points.clone());
+    // `Airfoil` closes its loop on demand, so it is accepted.
+    assert!(PanelLuSystem::try_new(&open).is_ok());
+
+    let mut crossed = points;
+    let mid = crossed.len() / 4;
+    crossed[mid].y = 0.2;
+    let crossed = crate::airfoil::Airfoil::new("crossed", crossed);
+    assert!(matches!(
+        PanelLuSystem::try_new(&crossed),
+        Err(SolveError::Geometry(
+            GeometryError::SelfIntersection { .. }
+        ))
+    ));
+
+    let mut reversed = params.panel_points();
+    reversed.reverse();
+    let reversed = crate::airfoil::Airfoil::new("reversed", reversed);
+    let sys = PanelLuSystem::try_new(&reversed).expect("usable");
+    assert!(
+        sys.warnings().contains(&GeometryWarning::ReversedOrdering)
+    );
+}
+
+#[test]
+fn failed_solves_say_why() {
+    // Out along the chord and back: no area, and nothing to solve.
+    let flat = crate::airfoil::Airfoil::new(
+        "flat",
+        [1.0, 0.5, 0.0, 0.5, 1.0]
+            .map(|x| Vec2::new(x, 0.0))
+            .to_vec(),
+    );
+    for method in
+        [PanelMethod::ConstantSource, PanelMethod::LinearVorticity]
+    {
+        assert_eq!(
+            PanelLuSystem::try_with_method(&flat, method).err(),
+            Some(SolveError::Geometry(GeometryError::ZeroArea))
+        );
+        let err = PanelLuSystem::unchecked(&flat, method).err();
+        assert!(
+            matches!(err, Some(SolveError::SingularMatrix { .. })),
+            "{method:?}: {err:?}"
+        );
+    }
+    assert_eq!(
+        try_compute_panel_solution(&flat, 4.0).err(),
+        Some(SolveError::Geometry(GeometryError::ZeroArea))
+    );
+    // The lenient entry point still hands back approximate coefficients.
+    let sol = compute_panel_solution(&flat, 4.0);
+    assert!(sol.x.is_empty());
+    assert_eq!(sol.coefficient_source, CoefficientSource::Approximate);
+
+    assert_eq!(
+        kutta_te_panel_indices(&[]),
+        Err(SolveError::KuttaPanels)
+    );
+
+    let params = NacaParams::default();
+    let sys = PanelLuSystem::new(&params).expect("panel system");
+    assert!(sys.try_panel_solution(4.0).is_ok());
+    assert_eq!(
+        sys.try_panel_solution(f32::NAN).err(),
+        Some(SolveError::NonFinite)
+    );
+}
@@ -1,8 +1,11 @@
-use crate::state::{FlowSettings, NacaParams};
+use crate::airfoil::AirfoilShape;
+use crate::math::Vec2;
+use crate::state::FlowSettings;
 
-use super::panel::PanelLuSystem;
+use super::panel::{PanelLuSystem, transfer_moment};
 use super::{
-    BoundaryLayerInputs, PanelSolution, compute_panel_solution,
+    BoundaryLayerInputs, CoefficientSource, PanelSolution, SolveError,
+    center_of_pressure, compute_approx_solution,
     estimate_boundary_layer,
 };
 
@@ -18,6 +21,13 @@ pub struct PolarRow {
     pub cm_c4: f32,
     pub cd_profile: Option<f32>,
     pub probable_stall: bool,
+    /// CM about `FlowSettings::moment_reference`, nose-up positive.
+    pub cm_ref: f32,
+    /// Center of pressure as a fraction of the chord; `None` near zero
+    /// lift.
+    pub x_cp: Option<f32>,
+    /// What `cl` and `cm_c4` come from.
+    pub coefficient_source: CoefficientSource,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -28,12 +38,61 @@ pub enum PolarMode {
     Approx,
 }
 
+/// An angle of attack the panel solver could not solve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolarError {
+    pub alpha_deg: f32,
+    pub error: SolveError,
+}
+
 #[derive(Clone, Debug)]
 pub struct PolarSweepResult {
     pub rows: Vec<PolarRow>,
-    /// True if `PolarMode::Panel` was requested but the solver fell back to
-    /// approximation for at least one alpha (e.g. singular/invalid solve).
-    pub used_fallback: bool,
+    /// Angles where `PolarMode::Panel` was requested but the solve
+    /// failed; their rows come from the approximate model instead.
+    pub errors: Vec<PolarError>,
+    /// Aerodynamic center as a fraction of the chord, from the slope
+    /// of CM_c/4 against CL; see `aerodynamic_center`.
+    pub x_ac: Option<f32>,
+}
+
+impl PolarSweepResult {
+    fn new(rows: Vec<PolarRow>, errors: Vec<PolarError>) -> Self {
+        Self {
+            x_ac: aerodynamic_center(&rows),
+            rows,
+            errors,
+        }
+    }
+
+    /// True if any row fell back to the approximate model.
+    pub fn used_fallback(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
+/// Aerodynamic center, x_ac/c = 1/4 - dCM_c/4 / dCL, from a least-squares
+/// fit over the rows without probable stall. `None` if they span too
+/// little lift to fit.
+pub fn aerodynamic_center(rows: &[PolarRow]) -> Option<f32> {
+    let attached: Vec<(f32, f32)> = rows
+        .iter()
+        .filter(|r| !r.probable_stall)
+        .map(|r| (r.cl, r.cm_c4))
+        .filter(|(cl, cm)| cl.is_finite() && cm.is_finite())
+        .collect();
+    if attached.len() < 2 {
+        return None;
+    }
+    let n = attached.len() as f32;
+    let mean_cl = attached.iter().map(|r| r.0).sum::<f32>() / n;
+    let mean_cm = attached.iter().map(|r| r.1).sum::<f32>() / n;
+    let (mut scl, mut scm) = (0.0, 0.0);
+    for (cl, cm) in &attached {
+        scl += (cl - mean_cl) * (cl - mean_cl);
+        scm += (cl - mean_cl) * (cm - mean_cm);
+    }
+    (scl > 1e-6).then(|| 0.25 - scm / scl)
 }
 
 pub fn default_polar_sweep() -> (f32, f32, f32) {
@@ -44,26 +103,81 @@ pub fn default_polar_sweep() -> (f32, f32, f32) {
     )
 }
 
-pub fn compute_polar_sweep(
-    params: &NacaParams,
+/// Single-threaded panel sweep; angles the panel solver cannot solve get
+/// approximate rows and an entry in `PolarSweepResult::errors`.
+pub fn compute_polar_sweep<A: AirfoilShape + Sync + ?Sized>(
+    airfoil: &A,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
     alpha_step_deg: f32,
-) -> Vec<PolarRow> {
-    compute_polar_sweep_with_system(
-        params,
+) -> PolarSweepResult {
+    compute_polar_sweep_parallel_with_system_mode(
+        airfoil,
         flow,
         alpha_min_deg,
         alpha_max_deg,
         alpha_step_deg,
         None,
+        Some(1),
+        PolarMode::Panel,
     )
 }
 
+/// Like `compute_polar_sweep`, but with no fallbacks: fails if the
+/// geometry is rejected by `PanelLuSystem::try_with_method` or at the
+/// first angle the panel solver cannot solve. On success `errors` is
+/// empty.
+pub fn try_compute_polar_sweep<A: AirfoilShape + ?Sized>(
+    airfoil: &A,
+    flow: &FlowSettings,
+    alpha_min_deg: f32,
+    alpha_max_deg: f32,
+    alpha_step_deg: f32,
+) -> Result<PolarSweepResult, SolveError> {
+    let (alphas, _) =
+        alpha_samples(alpha_min_deg, alpha_max_deg, alpha_step_deg);
+    if alphas.is_empty() {
+        return Ok(PolarSweepResult::new(Vec::new(), Vec::new()));
+    }
+    let system =
+        PanelLuSystem::try_with_method(airfoil, flow.panel_method)?;
+
+    let beta = (1.0 - flow.mach * flow.mach).clamp(0.05, 1.0).sqrt();
+    let bl_inputs = BoundaryLayerInputs::new(
+        flow.reynolds,
+        flow.mach,
+        flow.viscous,
+        flow.free_transition,
+        DEFAULT_FORCED_TRIP_X,
+    );
+    let rows = alphas
+        .iter()
+        .map(|&a| {
+            let sol = system.try_panel_solution(a)?;
+            Ok(polar_row(
+                &sol,
+                a,
+                beta,
+                &bl_inputs,
+                flow.moment_reference,
+            ))
+        })
+        .collect::<Result<_, SolveError>>()?;
+    Ok(PolarSweepResult::new(rows, Vec::new()))
+}
+
+/// Polar sweep in `mode`, optionally on a prebuilt `system`, spread over
+/// `threads` (all available by default).
+///
+/// In panel mode, every angle the solver cannot solve (all of them if
+/// the system cannot be built) gets an approximate row and an entry in
+/// `PolarSweepResult::errors`. The other sweep functions all end here.
 #[allow(clippy::too_many_arguments)]
-pub fn compute_polar_sweep_parallel_with_system_mode(
-    params: &NacaParams,
+pub fn compute_polar_sweep_parallel_with_system_mode<
+    A: AirfoilShape + Sync + ?Sized,
+>(
+    airfoil: &A,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
@@ -75,10 +189,7 @@ pub fn compute_polar_sweep_parallel_with_system_mode(
     let (alphas, capacity) =
         alpha_samples(alpha_min_deg, alpha_max_deg, alpha_step_deg);
     if alphas.is_empty() {
-        return PolarSweepResult {
-            rows: Vec::new(),
-            used_fallback: false,
-        };
+        return PolarSweepResult::new(Vec::new(), Vec::new());
     }
 
     if mode == PolarMode::Approx {
@@ -95,21 +206,27 @@ pub fn compute_polar_sweep_parallel_with_system_mode(
 
         let mut rows = Vec::with_capacity(capacity);
         for &a in &alphas {
-            let sol = super::panel::compute_approx_solution(params, a);
-            rows.push(polar_row(&sol, a, beta, bl_inputs));
+            let sol = compute_approx_solution(airfoil, a);
+            rows.push(polar_row(
+                &sol,
+                a,
+                beta,
+                bl_inputs,
+                flow.moment_reference,
+            ));
         }
-        return PolarSweepResult {
-            rows,
-            used_fallback: false,
-        };
+        return PolarSweepResult::new(rows, Vec::new());
     }
 
     // Panel mode.
     let owned_system;
     let system = match system {
-        Some(sys) => Some(sys),
+        Some(sys) => Ok(sys),
         None => {
-            owned_system = PanelLuSystem::new(params);
+            owned_system = PanelLuSystem::try_with_method(
+                airfoil,
+                flow.panel_method,
+            );
             owned_system.as_ref()
         }
     };
@@ -126,16 +243,27 @@ pub fn compute_polar_sweep_parallel_with_system_mode(
 
     // If we couldn't build a cached system, fall back to approximation for
     // all alphas.
-    let Some(system) = system else {
-        let mut rows = Vec::with_capacity(capacity);
-        for &a in &alphas {
-            let sol = super::panel::compute_approx_solution(params, a);
-            rows.push(polar_row(&sol, a, beta, bl_inputs));
+    let system = match system {
+        Ok(system) => system,
+        Err(error) => {
+            let mut rows = Vec::with_capacity(capacity);
+            let mut errors = Vec::with_capacity(capacity);
+            for &a in &alphas {
+                let sol = compute_approx_solution(airfoil, a);
+                rows.push(polar_row(
+                    &sol,
+                    a,
+                    beta,
+                    bl_inputs,
+                    flow.moment_reference,
+                ));
+                errors.push(PolarError {
+                    alpha_deg: a,
+                    error: error.clone(),
+                });
+            }
+            return PolarSweepResult::new(rows, errors);
         }
-        return PolarSweepResult {
-            rows,
-            used_fallback: true,
-        };
     };
 
     let available = std::thread::available_parallelism()
@@ -145,26 +273,24 @@ pub fn compute_polar_sweep_parallel_with_system_mode(
     thread_count = thread_count.min(alphas.len());
 
     if thread_count <= 1 {
-        let mut used_fallback = false;
+        let mut errors = Vec::new();
         let mut rows = Vec::with_capacity(capacity);
         for &a in &alphas {
-            let sol = system.panel_solution(params, a);
-            used_fallback |= sol.x.is_empty();
-            let sol = if sol.x.is_empty() {
-                super::panel::compute_approx_solution(params, a)
-            } else {
-                sol
-            };
-            rows.push(polar_row(&sol, a, beta, bl_inputs));
+            let (sol, error) = solve_or_approx(system, airfoil, a);
+            errors.extend(error);
+            rows.push(polar_row(
+                &sol,
+                a,
+                beta,
+                bl_inputs,
+                flow.moment_reference,
+            ));
         }
-        return PolarSweepResult {
-            rows,
-            used_fallback,
-        };
+        return PolarSweepResult::new(rows, errors);
     }
 
     let chunk_size = alphas.len().div_ceil(thread_count);
-    let mut chunks: Vec<(Vec<PolarRow>, bool)> =
+    let mut chunks: Vec<(Vec<PolarRow>, Vec<PolarError>)> =
         Vec::with_capacity(thread_count);
 
     std::thread::scope(|scope| {
@@ -178,50 +304,67 @@ pub fn compute_polar_sweep_parallel_with_system_mode(
             let alpha_slice = &alphas[start..end];
 
             handles.push(scope.spawn(move || {
-                let mut used_fallback = false;
+                let mut errors = Vec::new();
                 let mut rows = Vec::with_capacity(alpha_slice.len());
                 for &a in alpha_slice {
-                    let sol = system.panel_solution(params, a);
-                    used_fallback |= sol.x.is_empty();
-                    let sol = if sol.x.is_empty() {
-                        super::panel::compute_approx_solution(params, a)
-                    } else {
-                        sol
-                    };
-                    rows.push(polar_row(&sol, a, beta, bl_inputs));
+                    let (sol, error) =
+                        solve_or_approx(system, airfoil, a);
+                    errors.extend(error);
+                    rows.push(polar_row(
+                        &sol,
+                        a,
+                        beta,
+                        bl_inputs,
+                        flow.moment_reference,
+                    ));
                 }
-                (rows, used_fallback)
+                (rows, errors)
             }));
         }
 
         for h in handles {
-            chunks
-                .push(h.join().unwrap_or_else(|_| (Vec::new(), true)));
+            chunks.push(h.join().unwrap_or_else(|panic| {
+                std::panic::resume_unwind(panic)
+            }));
         }
     });
 
     let total: usize = chunks.iter().map(|c| c.0.len()).sum();
-    let used_fallback = chunks.iter().any(|c| c.1);
     let mut rows = Vec::with_capacity(total);
-    for (mut c, _) in chunks {
+    let mut errors = Vec::new();
+    for (mut c, mut e) in chunks {
         rows.append(&mut c);
+        errors.append(&mut e);
     }
 
-    PolarSweepResult {
-        rows,
-        used_fallback,
+    PolarSweepResult::new(rows, errors)
+}
+
+/// Panel solution at `alpha_deg`, or the approximate one and the reason
+/// the panel solve failed.
+fn solve_or_approx<A: AirfoilShape + ?Sized>(
+    system: &PanelLuSystem,
+    airfoil: &A,
+    alpha_deg: f32,
+) -> (PanelSolution, Option<PolarError>) {
+    match system.try_panel_solution(alpha_deg) {
+        Ok(sol) => (sol, None),
+        Err(error) => (
+            compute_approx_solution(airfoil, alpha_deg),
+            Some(PolarError { alpha_deg, error }),
+        ),
     }
 }
 
-pub fn compute_polar_sweep_parallel(
-    params: &NacaParams,
+pub fn compute_polar_sweep_parallel<A: AirfoilShape + Sync + ?Sized>(
+    airfoil: &A,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
     alpha_step_deg: f32,
-) -> Vec<PolarRow> {
+) -> PolarSweepResult {
     compute_polar_sweep_parallel_with_threads(
-        params,
+        airfoil,
         flow,
         alpha_min_deg,
         alpha_max_deg,
@@ -230,16 +373,18 @@ pub fn compute_polar_sweep_parallel(
     )
 }
 
-pub fn compute_polar_sweep_parallel_with_threads(
-    params: &NacaParams,
+pub fn compute_polar_sweep_parallel_with_threads<
+    A: AirfoilShape + Sync + ?Sized,
+>(
+    airfoil: &A,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
     alpha_step_deg: f32,
     threads: Option<usize>,
-) -> Vec<PolarRow> {
+) -> PolarSweepResult {
     compute_polar_sweep_parallel_with_system(
-        params,
+        airfoil,
         flow,
         alpha_min_deg,
         alpha_max_deg,
@@ -249,19 +394,19 @@ pub fn compute_polar_sweep_parallel_with_threads(
     )
 }
 
-pub fn compute_multi_polar_sweeps(
-    params: &NacaParams,
+pub fn compute_multi_polar_sweeps<A: AirfoilShape + Sync + ?Sized>(
+    airfoil: &A,
     flows: &[FlowSettings],
     alpha_min_deg: f32,
     alpha_max_deg: f32,
     alpha_step_deg: f32,
     threads: Option<usize>,
-) -> Vec<(FlowSettings, Vec<PolarRow>)> {
+) -> Vec<(FlowSettings, PolarSweepResult)> {
     let mut out = Vec::with_capacity(flows.len());
     for flow in flows {
-        let rows = if threads.unwrap_or(1) <= 1 {
+        let sweep = if threads.unwrap_or(1) <= 1 {
             compute_polar_sweep(
-                params,
+                airfoil,
                 flow,
                 alpha_min_deg,
                 alpha_max_deg,
@@ -269,7 +414,7 @@ pub fn compute_multi_polar_sweeps(
             )
         } else {
             compute_polar_sweep_parallel_with_threads(
-                params,
+                airfoil,
                 flow,
                 alpha_min_deg,
                 alpha_max_deg,
@@ -277,174 +422,69 @@ pub fn compute_multi_polar_sweeps(
                 threads,
             )
         };
-        out.push((flow.clone(), rows));
+        out.push((flow.clone(), sweep));
     }
     out
 }
 
-pub(crate) fn compute_polar_sweep_with_system(
-    params: &NacaParams,
-    flow: &FlowSettings,
-    alpha_min_deg: f32,
-    alpha_max_deg: f32,
-    alpha_step_deg: f32,
-    system: Option<&PanelLuSystem>,
-) -> Vec<PolarRow> {
-    let (alphas, capacity) =
-        alpha_samples(alpha_min_deg, alpha_max_deg, alpha_step_deg);
-    if alphas.is_empty() {
-        return Vec::new();
-    }
-
-    let owned_system;
-    let system = match system {
-        Some(sys) => Some(sys),
-        None => {
-            owned_system = PanelLuSystem::new(params);
-            owned_system.as_ref()
-        }
-    };
-
-    let beta = (1.0 - flow.mach * flow.mach).clamp(0.05, 1.0).sqrt();
-    let bl_inputs = BoundaryLayerInputs::new(
-        flow.reynolds,
-        flow.mach,
-        flow.viscous,
-        flow.free_transition,
-        DEFAULT_FORCED_TRIP_X,
-    );
-    let bl_inputs = &bl_inputs;
-
-    let mut rows = Vec::with_capacity(capacity);
-    for &a in &alphas {
-        let sol = system
-            .as_ref()
-            .map(|sys| sys.panel_solution(params, a))
-            .unwrap_or_else(|| compute_panel_solution(params, a));
-        rows.push(polar_row(&sol, a, beta, bl_inputs));
-    }
-    rows
-}
-
 fn polar_row(
     sol: &PanelSolution,
     alpha_deg: f32,
     beta: f32,
     bl_inputs: &BoundaryLayerInputs,
+    moment_reference: Vec2,
 ) -> PolarRow {
+    // Prandtl–Glauert scales every pressure coefficient alike, so x_cp
+    // and x_ac stay put as the Mach number changes.
     let cl = sol.cl().unwrap_or(f32::NAN) / beta;
-    let cm_c4 = sol.cm_c4().unwrap_or(f32::NAN);
+    let cm_c4 = sol.cm_c4().unwrap_or(f32::NAN) / beta;
+    let cm_ref = transfer_moment(
+        cm_c4,
+        cl,
+        alpha_deg,
+        sol.quarter_chord,
+        moment_reference,
+        sol.chord,
+    );
     let boundary_layer = estimate_boundary_layer(sol, bl_inputs);
 
     PolarRow {
         alpha_deg,
         cl,
         cm_c4,
+        cm_ref,
+        x_cp: center_of_pressure(cl, cm_c4),
         cd_profile: boundary_layer.as_ref().map(|b| b.cd_profile),
         probable_stall: boundary_layer
             .as_ref()
             .map(|b| b.probable_stall)
             .unwrap_or(false),
+        coefficient_source: sol.coefficient_source,
     }
 }
 
-pub fn compute_polar_sweep_parallel_with_system(
-    params: &NacaParams,
+/// `compute_polar_sweep_parallel_with_system_mode` in panel mode.
+pub fn compute_polar_sweep_parallel_with_system<
+    A: AirfoilShape + Sync + ?Sized,
+>(
+    airfoil: &A,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
     alpha_step_deg: f32,
     system: Option<&PanelLuSystem>,
     threads: Option<usize>,
-) -> Vec<PolarRow> {
-    let (alphas, capacity) =
-        alpha_samples(alpha_min_deg, alpha_max_deg, alpha_step_deg);
-    if alphas.is_empty() {
-        return Vec::new();
-    }
-
-    let owned_system;
-    let system = match system {
-        Some(sys) => Some(sys),
-        None => {
-            owned_system = PanelLuSystem::new(params);
-            owned_system.as_ref()
-        }
-    };
-
-    // If we couldn't build a cached system, fall back to the single-threaded
-    // path (which already has a non-system fallback per alpha).
-    let Some(system) = system else {
-        return compute_polar_sweep_with_system(
-            params,
-            flow,
-            alpha_min_deg,
-            alpha_max_deg,
-            alpha_step_deg,
-            None,
-        );
-    };
-
-    let beta = (1.0 - flow.mach * flow.mach).clamp(0.05, 1.0).sqrt();
-    let bl_inputs = BoundaryLayerInputs::new(
-        flow.reynolds,
-        flow.mach,
-        flow.viscous,
-        flow.free_transition,
-        DEFAULT_FORCED_TRIP_X,
-    );
-    let bl_inputs = &bl_inputs;
-
-    let available = std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(1);
-    let mut thread_count = threads.unwrap_or(available).max(1);
-    thread_count = thread_count.min(alphas.len());
-
-    if thread_count <= 1 {
-        let mut rows = Vec::with_capacity(capacity);
-        for &a in &alphas {
-            let sol = system.panel_solution(params, a);
-            rows.push(polar_row(&sol, a, beta, bl_inputs));
-        }
-        return rows;
-    }
-
-    let chunk_size = alphas.len().div_ceil(thread_count);
-    let mut chunks: Vec<Vec<PolarRow>> =
-        Vec::with_capacity(thread_count);
-
-    std::thread::scope(|scope| {
-        let mut handles = Vec::with_capacity(thread_count);
-        for chunk_idx in 0..thread_count {
-            let start = chunk_idx * chunk_size;
-            if start >= alphas.len() {
-                break;
-            }
-            let end = ((chunk_idx + 1) * chunk_size).min(alphas.len());
-            let alpha_slice = &alphas[start..end];
-
-            handles.push(scope.spawn(move || {
-                let mut rows = Vec::with_capacity(alpha_slice.len());
-                for &a in alpha_slice {
-                    let sol = system.panel_solution(params, a);
-                    rows.push(polar_row(&sol, a, beta, bl_inputs));
-                }
-                rows
-            }));
-        }
-
-        for h in handles {
-            chunks.push(h.join().unwrap_or_default());
-        }
-    });
-
-    let total: usize = chunks.iter().map(|c| c.len()).sum();
-    let mut rows = Vec::with_capacity(total);
-    for mut c in chunks {
-        rows.append(&mut c);
-    }
-    rows
+) -> PolarSweepResult {
+    compute_polar_sweep_parallel_with_system_mode(
+        airfoil,
+        flow,
+        alpha_min_deg,
+        alpha_max_deg,
+        alpha_step_deg,
+        system,
+        threads,
+        PolarMode::Panel,
+    )
 }
 
 fn alpha_samples(
@@ -0,0 +1,214 @@
+//! Thin-airfoil theory for arbitrary camber lines.
+//!
+//! With x = (1 - cos θ) / 2 along the chord, the vortex sheet on the mean
+//! line is expanded in Glauert's series. The first three coefficients
+//! give the whole lift and moment:
+//!
+//! - A0 = α - (1/π) ∫ dz/dx dθ
+//! - An = (2/π) ∫ dz/dx cos nθ dθ
+//! - CL = π (2 A0 + A1), CM_c/4 = (π/4) (A2 - A1)
+//!
+//! The integrals are taken exactly over a mean line that is straight
+//! between its sample points.
+
+use std::f32::consts::PI;
+
+use crate::airfoil::{AirfoilShape, Surfaces, normalize_loop};
+use crate::math::Vec2;
+
+/// Mean-line samples taken from a shape.
+const CAMBER_SAMPLES: usize = 121;
+
+/// Glauert coefficients of a camber line.
+///
+/// `Default` is the flat plate.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThinAirfoil {
+    /// (1/π) ∫ dz/dx dθ, so that A0 = α - `mean_slope` (radians).
+    mean_slope: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl ThinAirfoil {
+    /// Coefficients of a mean line given LE → TE.
+    ///
+    /// The chord runs from the first point to the last, and heights are
+    /// measured from it. `None` for fewer than two points or a zero
+    /// chord.
+    pub fn from_camber_line(points: &[Vec2]) -> Option<Self> {
+        let (&first, &last) = (points.first()?, points.last()?);
+        let chord = last - first;
+        let length_sq = chord.length_squared();
+        if length_sq <= 1e-12 {
+            return None;
+        }
+        // Chord frame, scaled to a unit chord.
+        let local = |p: Vec2| {
+            let d = p - first;
+            Vec2::new(d.dot(chord), chord.perp_dot(d)) / length_sq
+        };
+        let theta = |x: f32| (1.0 - 2.0 * x).clamp(-1.0, 1.0).acos();
+
+        let mut coeffs = Self::default();
+        for w in points.windows(2) {
+            let (a, b) = (local(w[0]), local(w[1]));
+            let dx = b.x - a.x;
+            if dx <= 0.0 {
+                continue;
+            }
+            let slope = (b.y - a.y) / dx;
+            let (t0, t1) = (theta(a.x), theta(b.x));
+            coeffs.mean_slope += slope * (t1 - t0) / PI;
+            coeffs.a1 += 2.0 * slope * (t1.sin() - t0.sin()) / PI;
+            coeffs.a2 +=
+                slope * ((2.0 * t1).sin() - (2.0 * t0).sin()) / PI;
+        }
+        Some(coeffs)
+    }
+
+    /// Coefficients of a shape's mean line, halfway between its
+    /// surfaces at cosine-spaced stations. Coordinate loops are first
+    /// mapped onto their own LE → TE chord, so any position, size or
+    /// incidence works. Falls back to the flat plate for degenerate
+    /// shapes.
+    pub fn from_airfoil<A: AirfoilShape + ?Sized>(airfoil: &A) -> Self {
+        let mut surfaces: Option<Surfaces> = None;
+        let camber: Vec<Vec2> = (0..CAMBER_SAMPLES)
+            .map(|i| {
+                let beta = i as f32 / (CAMBER_SAMPLES - 1) as f32;
+                let x_c = 0.5 * (1.0 - (PI * beta).cos());
+                let (upper, lower) = airfoil
+                    .analytic_surfaces(x_c)
+                    .unwrap_or_else(|| {
+                        surfaces
+                            .get_or_insert_with(|| {
+                                let (unit, _) = normalize_loop(
+                                    &airfoil.panel_points(),
+                                );
+                                Surfaces::from_loop(&unit)
+                            })
+                            .at(x_c)
+                    });
+                0.5 * (upper + lower)
+            })
+            .collect();
+        Self::from_camber_line(&camber).unwrap_or_default()
+    }
+
+    /// A0 at `alpha_deg`, in radians.
+    pub fn a0(&self, alpha_deg: f32) -> f32 {
+        alpha_deg.to_radians() - self.mean_slope
+    }
+
+    pub fn a1(&self) -> f32 {
+        self.a1
+    }
+
+    pub fn a2(&self) -> f32 {
+        self.a2
+    }
+
+    /// Angle of attack with no lift.
+    pub fn zero_lift_alpha_deg(&self) -> f32 {
+        (self.mean_slope - 0.5 * self.a1).to_degrees()
+    }
+
+    /// Ideal angle of attack: the flow meets the LE smoothly (A0 = 0).
+    pub fn ideal_alpha_deg(&self) -> f32 {
+        self.mean_slope.to_degrees()
+    }
+
+    /// CL = 2π (α - α_L0).
+    pub fn cl(&self, alpha_deg: f32) -> f32 {
+        PI * (2.0 * self.a0(alpha_deg) + self.a1)
+    }
+
+    /// Moment about the quarter chord, the same at every α.
+    pub fn cm_c4(&self) -> f32 {
+        0.25 * PI * (self.a2 - self.a1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::NacaParams;
+
+    fn parabola(h: f32) -> Vec<Vec2> {
+        (0..=200)
+            .map(|i| {
+                let x = i as f32 / 200.0;
+                Vec2::new(x, 4.0 * h * x * (1.0 - x))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parabolic_arc_matches_closed_form() {
+        // dz/dx = 4h cos θ: A1 = 4h, everything else zero.
+        let h = 0.05;
+        let thin = ThinAirfoil::from_camber_line(&parabola(h)).unwrap();
+        assert!(thin.ideal_alpha_deg().abs() < 1e-3);
+        assert!((thin.a1() - 4.0 * h).abs() < 1e-3, "{thin:?}");
+        assert!(thin.a2().abs() < 1e-3, "{thin:?}");
+        assert!(
+            (thin.zero_lift_alpha_deg() + (2.0 * h).to_degrees()).abs()
+                < 0.02
+        );
+        assert!((thin.cm_c4() + PI * h).abs() < 1e-3);
+        assert!((thin.cl(0.0) - 2.0 * PI * 2.0 * h).abs() < 2e-3);
+
+        // Moving and scaling the chord changes nothing.
+        let moved: Vec<Vec2> = parabola(h)
+            .iter()
+            .map(|p| 2.0 * *p + Vec2::new(0.3, -0.2))
+            .collect();
+        let same = ThinAirfoil::from_camber_line(&moved).unwrap();
+        assert!((same.a1() - thin.a1()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn naca_2412_matches_textbook_values() {
+        let params = NacaParams::from_naca4("2412").unwrap();
+        let thin = ThinAirfoil::from_airfoil(&params);
+        let alpha_l0 = thin.zero_lift_alpha_deg();
+        assert!((alpha_l0 + 2.08).abs() < 0.05, "α_L0 {alpha_l0}");
+        let cm = thin.cm_c4();
+        assert!((cm + 0.053).abs() < 0.002, "CM {cm}");
+        assert!(thin.ideal_alpha_deg() > 0.0);
+
+        let flat = ThinAirfoil::from_airfoil(
+            &NacaParams::from_naca4("0012").unwrap(),
+        );
+        assert!(flat.zero_lift_alpha_deg().abs() < 1e-3);
+        assert!(flat.cm_c4().abs() < 1e-4);
+    }
+
+    #[test]
+    fn coordinate_loops_are_read_on_their_own_chord() {
+        let params = NacaParams::from_naca4("2412").unwrap();
+        let loop_with = |f: &dyn Fn(Vec2) -> Vec2| {
+            let points =
+                params.panel_points().into_iter().map(f).collect();
+            ThinAirfoil::from_airfoil(&crate::airfoil::Airfoil::new(
+                "moved", points,
+            ))
+        };
+        let unit = loop_with(&|p| p);
+        let scaled = loop_with(&|p| 2.0 * p);
+        let shifted = loop_with(&|p| p + Vec2::new(0.5, 0.1));
+        for (name, thin) in [("scaled", scaled), ("shifted", shifted)] {
+            let (a, b) = (
+                unit.zero_lift_alpha_deg(),
+                thin.zero_lift_alpha_deg(),
+            );
+            assert!((a - b).abs() < 0.02, "{name} α_L0: {a} vs {b}");
+            let (a, b) = (unit.cm_c4(), thin.cm_c4());
+            assert!((a - b).abs() < 1e-3, "{name} CM: {a} vs {b}");
+            let (a, b) = (unit.cl(4.0), thin.cl(4.0));
+            assert!((a - b).abs() < 2e-3, "{name} CL: {a} vs {b}");
+        }
+        assert!((unit.zero_lift_alpha_deg() + 2.08).abs() < 0.1);
+    }
+}
@@ -0,0 +1,153 @@
+//! Panel solvers against exact conformal-mapping solutions.
+//!
+//! Kármán–Trefftz sections have closed-form potential flow, so the
+//! error of a panel solution can be measured directly and tracked as
+//! the paneling is refined.
+
+use crate::airfoil::{AirfoilShape, KarmanTrefftz};
+use crate::math::Vec2;
+
+use super::panel::{PanelLuSystem, PanelMethod, SolveError};
+
+/// Exact-flow samples the panel Cp is compared against.
+const EXACT_SAMPLES: usize = 4000;
+
+/// Error of one panel solution against the exact flow.
+#[derive(Clone, Debug)]
+pub struct ValidationPoint {
+    /// Points per surface requested from the shape.
+    pub num_points: usize,
+    /// Panels in the solver loop.
+    pub panels: usize,
+    pub cl: f32,
+    pub cl_exact: f32,
+    /// |CL - CL_exact|.
+    pub cl_error: f32,
+    /// RMS of the Cp error over the solver's surface points.
+    pub cp_rms_error: f32,
+    /// Largest Cp error at any surface point.
+    pub cp_max_error: f32,
+}
+
+/// Solves `shape` at `alpha_deg` with `method` for each entry of
+/// `num_points` and reports the error against the exact flow.
+///
+/// Panel Cp is compared at the solver's own surface points with the
+/// exact Cp at the nearest point of the exact surface. Returns one
+/// entry per count, in order; a count that fails to solve keeps its
+/// place with the reason.
+pub fn validate_karman_trefftz(
+    shape: &KarmanTrefftz,
+    alpha_deg: f32,
+    method: PanelMethod,
+    num_points: &[usize],
+) -> Vec<Result<ValidationPoint, SolveError>> {
+    let exact = shape.exact_flow(alpha_deg, EXACT_SAMPLES);
+    num_points
+        .iter()
+        .map(|&count| {
+            let shape = KarmanTrefftz {
+                num_points: count,
+                ..shape.clone()
+            };
+            let system =
+                PanelLuSystem::try_with_method(&shape, method)?;
+            let sol = system.try_panel_solution(alpha_deg)?;
+            let cl = sol.cl().ok_or(SolveError::NonFinite)?;
+
+            let errors: Vec<f32> = sol
+                .surface_points
+                .iter()
+                .zip(&sol.cp)
+                .map(|(&p, &cp)| {
+                    (cp - exact_cp_at(&exact.points, &exact.cp, p))
+                        .abs()
+                })
+                .collect();
+            let n = errors.len().max(1) as f32;
+            let rms =
+                (errors.iter().map(|e| e * e).sum::<f32>() / n).sqrt();
+            let max = errors.iter().copied().fold(0.0, f32::max);
+            Ok(ValidationPoint {
+                num_points: count,
+                panels: shape.panel_points().len() - 1,
+                cl,
+                cl_exact: exact.cl,
+                cl_error: (cl - exact.cl).abs(),
+                cp_rms_error: rms,
+                cp_max_error: max,
+            })
+        })
+        .collect()
+}
+
+/// Cp on the exact polyline at the point nearest `p`, interpolated
+/// along the closest segment.
+fn exact_cp_at(points: &[Vec2], cp: &[f32], p: Vec2) -> f32 {
+    let mut best = (f32::INFINITY, cp.first().copied().unwrap_or(0.0));
+    for (w, c) in points.windows(2).zip(cp.windows(2)) {
+        let d = w[1] - w[0];
+        let t = ((p - w[0]).dot(d) / d.length_squared().max(1e-12))
+            .clamp(0.0, 1.0);
+        let distance = p.distance_squared(w[0] + t * d);
+        if distance < best.0 {
+            best = (distance, c[0] + t * (c[1] - c[0]));
+        }
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panel_errors_shrink_with_refinement() {
+        let shape = KarmanTrefftz::new(Vec2::new(-0.08, 0.04), 8.0);
+        for method in
+            [PanelMethod::ConstantSource, PanelMethod::LinearVorticity]
+        {
+            let points = validate_karman_trefftz(
+                &shape,
+                4.0,
+                method,
+                &[40, 160],
+            );
+            assert_eq!(points.len(), 2);
+            let [coarse, fine] = [&points[0], &points[1]]
+                .map(|p| p.as_ref().expect("solved"));
+            assert!(fine.panels > coarse.panels);
+            assert!(fine.cl_exact > 0.0);
+            assert!(
+                fine.cp_rms_error < coarse.cp_rms_error,
+                "{method:?}: {coarse:?} vs {fine:?}"
+            );
+            assert!(
+                fine.cl_error < coarse.cl_error,
+                "{method:?}: {coarse:?} vs {fine:?}"
+            );
+            assert!(
+                fine.cl_error < 0.01 * fine.cl_exact,
+                "{method:?}: {fine:?}"
+            );
+            assert!(fine.cp_rms_error < 0.01, "{method:?}: {fine:?}");
+        }
+    }
+    #[test]
+    fn failed_counts_are_reported_in_place() {
+        let shape = KarmanTrefftz::new(Vec2::new(-0.08, 0.04), 8.0);
+        let points = validate_karman_trefftz(
+            &shape,
+            f32::NAN,
+            PanelMethod::default(),
+            &[40, 160],
+        );
+        assert_eq!(points.len(), 2);
+        for point in &points {
+            assert!(
+                matches!(point, Err(SolveError::NonFinite)),
+                "{point:?}"
+            );
+        }
+    }
+}
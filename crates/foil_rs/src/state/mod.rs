@@ -1,7 +1,10 @@
 use std::f32::consts::PI;
 
+use crate::math::Vec2;
+use crate::solvers::PanelMethod;
+
 /// Parameters for a NACA 4-digit airfoil.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct NacaParams {
     /// First digit: maximum camber in % of chord (0–9).
     pub m_digit: f32,
@@ -81,6 +84,11 @@ pub struct FlowSettings {
     pub mach: f32,
     pub viscous: bool,
     pub free_transition: bool,
+    /// Inviscid formulation for panel solves and polars.
+    pub panel_method: PanelMethod,
+    /// Point polar rows report `cm_ref` about (hinge line, CG), in
+    /// section coordinates; the quarter chord by default.
+    pub moment_reference: Vec2,
 }
 
 impl Default for FlowSettings {
@@ -91,6 +99,8 @@ impl Default for FlowSettings {
             mach: 0.10,
             viscous: true,
             free_transition: true,
+            panel_method: PanelMethod::default(),
+            moment_reference: Vec2::new(0.25, 0.0),
         }
     }
 }
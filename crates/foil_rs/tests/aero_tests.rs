@@ -1,6 +1,12 @@
+use foil_rs::airfoil::{
+    Airfoil, AirfoilShape, build_naca_body_geometry_sharp_te, parse_dat,
+};
+use foil_rs::math::Vec2;
 use foil_rs::solvers::{
-    BoundaryLayerInputs, compute_panel_solution, compute_polar_sweep,
-    estimate_boundary_layer,
+    BoundaryLayerInputs, CoefficientSource, PanelMethod, PolarMode,
+    SolveError, compute_panel_solution, compute_polar_sweep,
+    compute_polar_sweep_parallel_with_system_mode,
+    estimate_boundary_layer, try_compute_polar_sweep,
 };
 use foil_rs::state::{FlowSettings, NacaParams, reference_coeffs};
 
@@ -29,7 +35,8 @@ fn panel_solver_reports_lift_negative_at_negative_alpha() {
     let params = NacaParams::default();
     let sol = compute_panel_solution(&params, -4.0);
     let cl = sol.cl().unwrap_or(0.0);
-    assert!(cl < -0.3, "expected negative lift at -4deg, got {}", cl);
+    // Inviscid 2412: CL ≈ 0.255 + 0.12 · α[deg] ≈ -0.22 at -4°.
+    assert!(cl < -0.15, "expected negative lift at -4deg, got {}", cl);
 }
 
 #[test]
@@ -191,11 +198,17 @@ fn naca0012_lift_is_approximately_antisymmetric_inviscid() {
 fn polar_sweep_has_expected_count_and_sorted_alphas() {
     let params = NacaParams::default();
     let flow = FlowSettings::default();
-    let rows = compute_polar_sweep(&params, &flow, -10.0, 15.0, 0.5);
+    let sweep = compute_polar_sweep(&params, &flow, -10.0, 15.0, 0.5);
+    assert!(sweep.errors.is_empty(), "{:?}", sweep.errors);
+    let rows = sweep.rows;
 
     assert_eq!(rows.len(), 51, "unexpected polar row count");
     assert!((rows[0].alpha_deg + 10.0).abs() < 1e-5);
     assert!((rows[rows.len() - 1].alpha_deg - 15.0).abs() < 1e-5);
+    assert!(rows.iter().all(|row| row.coefficient_source
+        == CoefficientSource::SurfacePressure(
+            PanelMethod::ConstantSource
+        )));
 
     for w in rows.windows(2) {
         assert!(
@@ -206,3 +219,170 @@ fn polar_sweep_has_expected_count_and_sorted_alphas() {
         );
     }
 }
+
+#[test]
+fn sharp_te_loop_repeats_the_first_point() {
+    let params = NacaParams::default();
+    let pts = build_naca_body_geometry_sharp_te(&params);
+    // num_points per surface sharing the LE, plus the closing point.
+    assert_eq!(pts.len(), 2 * params.num_points);
+    assert_eq!(pts.first(), pts.last());
+}
+
+#[test]
+fn imported_dat_runs_through_polar_sweep() {
+    let params =
+        NacaParams::from_naca4("0012").expect("parse NACA 0012");
+
+    // Write the section in Selig order (TE → upper → LE → lower → TE).
+    let mut text = String::from("NACA 0012 (imported)\n");
+    for p in build_naca_body_geometry_sharp_te(&params).iter().rev() {
+        text.push_str(&format!("{:.6} {:.6}\n", p.x, p.y));
+    }
+    let airfoil = Airfoil::from(parse_dat(&text).expect("parse .dat"));
+    assert_eq!(airfoil.name(), "NACA 0012 (imported)");
+
+    let flow = FlowSettings::default();
+    let sweep = compute_polar_sweep(&airfoil, &flow, -4.0, 4.0, 4.0);
+    assert!(!sweep.used_fallback(), "{:?}", sweep.errors);
+    let rows = sweep.rows;
+    assert_eq!(rows.len(), 3);
+    assert!(
+        rows[1].cl.abs() < 0.05,
+        "expected near-zero lift at 0 deg, got {}",
+        rows[1].cl
+    );
+    assert!(
+        (rows[0].cl + rows[2].cl).abs() < 0.10,
+        "expected antisymmetric lift, got {} and {}",
+        rows[0].cl,
+        rows[2].cl
+    );
+}
+
+#[test]
+fn moments_move_to_any_reference_point() {
+    let params =
+        NacaParams::from_naca4("2412").expect("parse NACA 2412");
+    let hinge = Vec2::new(0.7, 0.0);
+    let flow = FlowSettings {
+        mach: 0.0,
+        moment_reference: hinge,
+        ..Default::default()
+    };
+    let sweep = compute_polar_sweep_parallel_with_system_mode(
+        &params,
+        &flow,
+        -4.0,
+        8.0,
+        1.0,
+        None,
+        Some(1),
+        PolarMode::Panel,
+    );
+
+    // Lift ahead of an aft hinge pitches the nose up about it.
+    let row = &sweep.rows[8];
+    let expected =
+        row.cm_c4 + 0.45 * row.cl * row.alpha_deg.to_radians().cos();
+    assert!((row.cm_ref - expected).abs() < 1e-4, "{row:?}");
+
+    // Direct integration about the hinge agrees with the moved moment.
+    let sol = compute_panel_solution(&params, row.alpha_deg);
+    let direct = sol.cm_about(hinge).expect("moment");
+    assert!((direct - row.cm_ref).abs() < 0.005, "{direct} vs {row:?}");
+
+    // Nose-down camber moment puts the center of pressure aft of c/4,
+    // moving forward towards it as lift grows.
+    let (low, high) = (sweep.rows[5].x_cp, sweep.rows[12].x_cp);
+    let (low, high) = (low.expect("x_cp"), high.expect("x_cp"));
+    assert!(low > high && high > 0.25, "x_cp {low} then {high}");
+
+    let x_ac = sweep.x_ac.expect("aerodynamic center");
+    assert!((x_ac - 0.25).abs() < 0.02, "x_ac {x_ac}");
+}
+
+#[test]
+fn compressibility_leaves_centers_in_place() {
+    let params =
+        NacaParams::from_naca4("2412").expect("parse NACA 2412");
+    let sweep = |mach: f32| {
+        let flow = FlowSettings {
+            mach,
+            ..Default::default()
+        };
+        compute_polar_sweep_parallel_with_system_mode(
+            &params,
+            &flow,
+            0.0,
+            8.0,
+            1.0,
+            None,
+            Some(1),
+            PolarMode::Panel,
+        )
+    };
+    let (low, high) = (sweep(0.1), sweep(0.5));
+    let (row_low, row_high) = (&low.rows[4], &high.rows[4]);
+    assert_eq!(row_low.alpha_deg, 4.0);
+    assert!(row_high.cl > 1.1 * row_low.cl, "{row_high:?}");
+    let (x_low, x_high) =
+        (row_low.x_cp.unwrap(), row_high.x_cp.unwrap());
+    assert!((x_low - x_high).abs() < 1e-3, "x_cp {x_low} vs {x_high}");
+    let (ac_low, ac_high) = (low.x_ac.unwrap(), high.x_ac.unwrap());
+    assert!(
+        (ac_low - ac_high).abs() < 1e-3,
+        "x_ac {ac_low} vs {ac_high}"
+    );
+}
+
+#[test]
+fn sweeps_record_why_each_alpha_fell_back() {
+    let flow = FlowSettings::default();
+    let params = NacaParams::default();
+    let sweep = |airfoil: &(dyn AirfoilShape + Sync)| {
+        compute_polar_sweep_parallel_with_system_mode(
+            airfoil,
+            &flow,
+            -2.0,
+            2.0,
+            1.0,
+            None,
+            Some(2),
+            PolarMode::Panel,
+        )
+    };
+    let res = sweep(&params);
+    assert!(res.errors.is_empty() && !res.used_fallback());
+    let strict =
+        try_compute_polar_sweep(&params, &flow, -2.0, 2.0, 1.0)
+            .expect("clean sweep");
+    assert!(strict.errors.is_empty());
+    assert_eq!(strict.x_ac, res.x_ac);
+    assert!(strict.x_ac.is_some());
+    for (a, b) in strict.rows.iter().zip(&res.rows) {
+        assert!((a.cl - b.cl).abs() < 1e-6);
+    }
+
+    // A loop with no area: every α falls back, each with its reason.
+    let flat = Airfoil::new(
+        "flat",
+        [1.0, 0.5, 0.0, 0.5, 1.0]
+            .map(|x| Vec2::new(x, 0.0))
+            .to_vec(),
+    );
+    let res = sweep(&flat);
+    assert!(res.used_fallback());
+    assert_eq!(res.errors.len(), res.rows.len());
+    for (row, err) in res.rows.iter().zip(&res.errors) {
+        assert_eq!(row.alpha_deg, err.alpha_deg);
+        assert_eq!(
+            row.coefficient_source,
+            CoefficientSource::Approximate
+        );
+        assert!(matches!(err.error, SolveError::Geometry(_)));
+    }
+    assert!(
+        try_compute_polar_sweep(&flat, &flow, -2.0, 2.0, 1.0).is_err()
+    );
+}
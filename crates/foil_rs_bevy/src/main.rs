@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use bevy::window::{Window, WindowPlugin, WindowResolution};
 
 use foil_rs_bevy::{plotter, state, ui, views};
-use state::{FlowSettings, NacaParams};
+use state::{FlowSettings, SectionParams};
 use ui::VisualMode;
 
 fn main() {
@@ -16,7 +16,7 @@ fn main() {
         .insert_resource(theme_mode)
         .insert_resource(UiTheme(ui::theme_props_for(theme_mode)))
         // State
-        .insert_resource(NacaParams::default())
+        .insert_resource(SectionParams::default())
         .insert_resource(FlowSettings::default())
         .insert_resource(ui::PolarSweepSettings::default())
         .insert_resource(VisualMode::Field)
@@ -66,6 +66,7 @@ fn main() {
                 ui::update_left_panel_visibility,
                 ui::update_panel_count_text,
                 ui::handle_export_polars_button,
+                ui::handle_export_dat_button,
                 ui::update_export_status_text,
                 ui::handle_view_buttons,
                 ui::handle_section_toggle_buttons,
@@ -77,6 +78,7 @@ fn main() {
         .add_systems(
             Update,
             (
+                ui::update_blend_partner_text,
                 ui::handle_coeff_mode_buttons,
                 ui::update_coeff_mode_button_styles,
                 ui::update_fallback_warning_badge,
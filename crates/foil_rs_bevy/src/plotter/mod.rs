@@ -5,6 +5,8 @@ use bevy::{
     text::{Justify, TextColor, TextFont, TextLayout},
 };
 
+use crate::solvers::panel::cp_plot_y;
+
 /// Stores spawned entities used as labels for the Cp plot, so we can cleanly
 /// despawn and refresh them each frame.
 #[derive(Resource, Default)]
@@ -77,7 +79,7 @@ pub fn refresh_cp_labels(
 
     // Cp ticks
     for &cp in cp_ticks {
-        let world_y = base_y - cp * 120.0; // match scale_y
+        let world_y = base_y + cp_plot_y(cp) * 120.0; // match scale_y
         let pos = Vec3::new(-0.5 * 450.0 - 32.0, world_y, 1.0);
         let text = format!("{:.1}", cp);
         let ent = commands
@@ -1,13 +1,97 @@
 use bevy::prelude::{Deref, DerefMut, Resource};
 
+use foil_rs::airfoil::{Blended, FlapParams, Flapped};
+use foil_rs::solvers::panel::PanelMethod;
 pub use foil_rs::state::{cl_thin, reference_coeffs};
 
-#[derive(Resource, Clone, Deref, DerefMut)]
-pub struct NacaParams(pub foil_rs::state::NacaParams);
+/// Second section mixed into the main one (XFoil `INTE`).
+#[derive(Clone, Debug)]
+pub struct BlendParams {
+    /// NACA 4-digit partner; its point count follows the main section.
+    pub partner: foil_rs::state::NacaParams,
+    /// Weight of the partner in percent; outside 0..100 extrapolates.
+    pub percent: f32,
+}
 
-impl Default for NacaParams {
+impl Default for BlendParams {
     fn default() -> Self {
-        Self(foil_rs::state::NacaParams::default())
+        Self {
+            partner: foil_rs::state::NacaParams::from_naca4("4415")
+                .expect("valid NACA code"),
+            percent: 0.0,
+        }
+    }
+}
+
+/// The section shown and analyzed: a NACA 4-digit airfoil, blended with
+/// a partner and then flapped.
+pub type Section = Flapped<
+    Blended<foil_rs::state::NacaParams, foil_rs::state::NacaParams>,
+>;
+
+/// NACA 4-digit parameters plus the plain-flap and blend settings.
+///
+/// Derefs to the NACA parameters, which most of the UI edits.
+#[derive(Resource, Clone, Default, Deref, DerefMut)]
+pub struct SectionParams {
+    #[deref]
+    pub naca: foil_rs::state::NacaParams,
+    pub flap: FlapParams,
+    pub blend: BlendParams,
+}
+
+impl SectionParams {
+    /// The section the solvers and the renderer work on.
+    pub fn section(&self) -> Section {
+        let mut partner = self.blend.partner.clone();
+        partner.num_points = self.naca.num_points;
+        Flapped::new(
+            Blended::new(
+                self.naca.clone(),
+                partner,
+                self.blend.percent / 100.0,
+            ),
+            self.flap.clone(),
+        )
+    }
+}
+
+/// Cache key for everything that changes the section's panel solution.
+///
+/// Shared by the view and table caches so a new setting only has to be
+/// added here.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SectionKey {
+    m: u8,
+    p: u8,
+    t: u8,
+    num_points: usize,
+    flap_bits: (u32, u32),
+    blend_bits: ([u32; 3], u32),
+    method: PanelMethod,
+}
+
+impl SectionKey {
+    pub fn new(params: &SectionParams, method: PanelMethod) -> Self {
+        Self {
+            m: params.m_digit.round().clamp(0.0, 9.0) as u8,
+            p: params.p_digit.round().clamp(0.0, 9.0) as u8,
+            t: params.t_digits.round().clamp(0.0, 99.0) as u8,
+            num_points: params.num_points,
+            flap_bits: (
+                params.flap.hinge_x.to_bits(),
+                params.flap.deflection_deg.to_bits(),
+            ),
+            blend_bits: (
+                [
+                    params.blend.partner.m_digit.to_bits(),
+                    params.blend.partner.p_digit.to_bits(),
+                    params.blend.partner.t_digits.to_bits(),
+                ],
+                params.blend.percent.to_bits(),
+            ),
+            method,
+        }
     }
 }
 
@@ -12,6 +12,7 @@ use bevy::{
     },
 };
 
+use crate::solvers::panel::PanelMethod;
 use crate::state::FlowSettings;
 
 use super::super::types::NumericField;
@@ -31,6 +32,8 @@ pub(super) fn spawn_flow_section(
     theme_mode: super::super::types::UiColorThemeMode,
 ) {
     let flow_open = sections.is_open(PanelSection::Flow);
+    let linear_vorticity =
+        flow.panel_method == PanelMethod::LinearVorticity;
     panel
         .spawn((
             Node {
@@ -277,5 +280,47 @@ pub(super) fn spawn_flow_section(
                         ThemedText,
                     ));
                 });
+
+            flow_panel.spawn(Text::new("Inviscid model"));
+            flow_panel
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        padding: UiRect::axes(
+                            Val::Px(8.0),
+                            Val::Px(6.0),
+                        ),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border_radius: BorderRadius::all(Val::Px(
+                            config::BUTTON_RADIUS,
+                        )),
+                        ..default()
+                    },
+                    ThemeBackgroundColor(if linear_vorticity {
+                        tokens::BUTTON_PRIMARY_BG
+                    } else {
+                        tokens::BUTTON_BG
+                    }),
+                    ThemeFontColor(if linear_vorticity {
+                        tokens::BUTTON_PRIMARY_TEXT
+                    } else {
+                        tokens::BUTTON_TEXT
+                    }),
+                    Button,
+                    FlowToggleKind::LinearVorticity,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(
+                            style::flow_toggle_label(
+                                FlowToggleKind::LinearVorticity,
+                                linear_vorticity,
+                            )
+                            .to_string(),
+                        ),
+                        ThemedText,
+                    ));
+                });
         });
 }
@@ -12,12 +12,13 @@ use bevy::{
     },
 };
 
-use crate::state::NacaParams;
+use crate::state::SectionParams;
 
+use super::super::systems::blend_partner_label;
 use super::super::types::NumericField;
 use super::super::types::{
-    InputSlider, PanelSection, PanelSections, SectionContent,
-    SectionToggle,
+    BlendPartnerText, ExportDatButton, InputSlider, PanelSection,
+    PanelSections, SectionContent, SectionToggle,
 };
 use super::super::{config, style};
 use super::numeric::spawn_numeric_input;
@@ -25,7 +26,7 @@ use super::numeric::spawn_numeric_input;
 pub(super) fn spawn_geometry_section(
     panel: &mut ChildSpawnerCommands<'_>,
     asset_server: &AssetServer,
-    params: &NacaParams,
+    params: &SectionParams,
     sections: &PanelSections,
     theme_mode: super::super::types::UiColorThemeMode,
 ) {
@@ -95,7 +96,7 @@ pub(super) fn spawn_geometry_section(
                 observe(slider_self_update),
                 observe(
                     |change: On<ValueChange<f32>>,
-                     mut p: ResMut<NacaParams>| {
+                     mut p: ResMut<SectionParams>| {
                         p.m_digit =
                             change.value.round().clamp(0.0, 9.0);
                     },
@@ -127,7 +128,7 @@ pub(super) fn spawn_geometry_section(
                 observe(slider_self_update),
                 observe(
                     |change: On<ValueChange<f32>>,
-                     mut p: ResMut<NacaParams>| {
+                     mut p: ResMut<SectionParams>| {
                         p.p_digit =
                             change.value.round().clamp(0.0, 9.0);
                     },
@@ -159,7 +160,7 @@ pub(super) fn spawn_geometry_section(
                 observe(slider_self_update),
                 observe(
                     |change: On<ValueChange<f32>>,
-                     mut p: ResMut<NacaParams>| {
+                     mut p: ResMut<SectionParams>| {
                         p.t_digits =
                             change.value.round().clamp(1.0, 40.0);
                     },
@@ -176,5 +177,191 @@ pub(super) fn spawn_geometry_section(
                 0,
                 true,
             );
+
+            geo.spawn(Text::new("Flap deflection (deg, TE down +)"));
+            geo.spawn((
+                slider(
+                    SliderProps {
+                        value: params.flap.deflection_deg,
+                        min: -30.0,
+                        max: 30.0,
+                    },
+                    (SliderStep(1.0), SliderPrecision(0)),
+                ),
+                InputSlider,
+                observe(slider_self_update),
+                observe(
+                    |change: On<ValueChange<f32>>,
+                     mut p: ResMut<SectionParams>| {
+                        p.flap.deflection_deg =
+                            change.value.round().clamp(-30.0, 30.0);
+                    },
+                ),
+            ));
+            spawn_numeric_input(
+                geo,
+                asset_server,
+                theme_mode,
+                NumericField::FlapDeflectionDeg,
+                format!("{:.1}", params.flap.deflection_deg),
+                -30.0,
+                30.0,
+                1,
+                false,
+            );
+
+            geo.spawn((
+                Text::new(blend_partner_label(params)),
+                BlendPartnerText,
+            ));
+            geo.spawn(Text::new("Partner camber m (%)"));
+            geo.spawn((
+                slider(
+                    SliderProps {
+                        value: params.blend.partner.m_digit,
+                        min: 0.0,
+                        max: 9.0,
+                    },
+                    (SliderStep(1.0), SliderPrecision(0)),
+                ),
+                InputSlider,
+                observe(slider_self_update),
+                observe(
+                    |change: On<ValueChange<f32>>,
+                     mut p: ResMut<SectionParams>| {
+                        p.blend.partner.m_digit =
+                            change.value.round().clamp(0.0, 9.0);
+                    },
+                ),
+            ));
+            spawn_numeric_input(
+                geo,
+                asset_server,
+                theme_mode,
+                NumericField::BlendPartnerMDigit,
+                format!("{:.0}", params.blend.partner.m_digit),
+                0.0,
+                9.0,
+                0,
+                true,
+            );
+
+            geo.spawn(Text::new(
+                "Partner camber position p (x/c * 10)",
+            ));
+            geo.spawn((
+                slider(
+                    SliderProps {
+                        value: params.blend.partner.p_digit,
+                        min: 0.0,
+                        max: 9.0,
+                    },
+                    (SliderStep(1.0), SliderPrecision(0)),
+                ),
+                InputSlider,
+                observe(slider_self_update),
+                observe(
+                    |change: On<ValueChange<f32>>,
+                     mut p: ResMut<SectionParams>| {
+                        p.blend.partner.p_digit =
+                            change.value.round().clamp(0.0, 9.0);
+                    },
+                ),
+            ));
+            spawn_numeric_input(
+                geo,
+                asset_server,
+                theme_mode,
+                NumericField::BlendPartnerPDigit,
+                format!("{:.0}", params.blend.partner.p_digit),
+                0.0,
+                9.0,
+                0,
+                true,
+            );
+
+            geo.spawn(Text::new("Partner thickness t (%)"));
+            geo.spawn((
+                slider(
+                    SliderProps {
+                        value: params.blend.partner.t_digits,
+                        min: 1.0,
+                        max: 40.0,
+                    },
+                    (SliderStep(1.0), SliderPrecision(0)),
+                ),
+                InputSlider,
+                observe(slider_self_update),
+                observe(
+                    |change: On<ValueChange<f32>>,
+                     mut p: ResMut<SectionParams>| {
+                        p.blend.partner.t_digits =
+                            change.value.round().clamp(1.0, 40.0);
+                    },
+                ),
+            ));
+            spawn_numeric_input(
+                geo,
+                asset_server,
+                theme_mode,
+                NumericField::BlendPartnerTDigits,
+                format!("{:.0}", params.blend.partner.t_digits),
+                1.0,
+                40.0,
+                0,
+                true,
+            );
+
+            geo.spawn(Text::new("Blend with partner (%)"));
+            geo.spawn((
+                slider(
+                    SliderProps {
+                        value: params.blend.percent,
+                        min: -50.0,
+                        max: 150.0,
+                    },
+                    (SliderStep(5.0), SliderPrecision(0)),
+                ),
+                InputSlider,
+                observe(slider_self_update),
+                observe(
+                    |change: On<ValueChange<f32>>,
+                     mut p: ResMut<SectionParams>| {
+                        p.blend.percent =
+                            change.value.round().clamp(-50.0, 150.0);
+                    },
+                ),
+            ));
+            spawn_numeric_input(
+                geo,
+                asset_server,
+                theme_mode,
+                NumericField::BlendPercent,
+                format!("{:.0}", params.blend.percent),
+                -50.0,
+                150.0,
+                0,
+                false,
+            );
+
+            geo.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border_radius: BorderRadius::all(Val::Px(
+                        config::BUTTON_RADIUS,
+                    )),
+                    ..default()
+                },
+                ThemeBackgroundColor(tokens::BUTTON_BG),
+                ThemeFontColor(tokens::BUTTON_TEXT),
+                Button,
+                ExportDatButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((Text::new("Export .dat"), ThemedText));
+            });
         });
 }
@@ -11,7 +11,7 @@ use bevy::feathers::theme::{ThemeBackgroundColor, ThemeBorderColor};
 use bevy::feathers::tokens;
 use bevy::prelude::*;
 
-use crate::state::{FlowSettings, NacaParams};
+use crate::state::{FlowSettings, SectionParams};
 
 use super::types::{
     ExportStatus, LeftPanelMainControls, LeftPanelPanelControls,
@@ -22,7 +22,7 @@ use super::types::{
 pub fn setup_ui(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     sweep: Res<PolarSweepSettings>,
     mode: Res<VisualMode>,
@@ -50,7 +50,7 @@ pub fn setup_ui(
 pub(super) fn spawn_ui_root(
     commands: &mut Commands,
     asset_server: &AssetServer,
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
     sweep: &PolarSweepSettings,
     mode: VisualMode,
@@ -135,7 +135,7 @@ pub(super) fn spawn_ui_root(
 fn spawn_left_panel(
     panel: &mut ChildSpawnerCommands<'_>,
     asset_server: &AssetServer,
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
     sweep: &PolarSweepSettings,
     mode: VisualMode,
@@ -12,14 +12,14 @@ use bevy::{
     },
 };
 
-use crate::{airfoil::build_naca_body_geometry, state::NacaParams};
+use crate::{airfoil::AirfoilShape, state::SectionParams};
 
 use super::super::config;
 use super::super::types::PanelCountText;
 
 pub(super) fn spawn_panel_settings(
     panel: &mut ChildSpawnerCommands<'_>,
-    params: &NacaParams,
+    params: &SectionParams,
 ) {
     panel
         .spawn(Node {
@@ -48,7 +48,7 @@ pub(super) fn spawn_panel_settings(
                 observe(slider_self_update),
                 observe(
                     |change: On<ValueChange<f32>>,
-                     mut p: ResMut<NacaParams>| {
+                     mut p: ResMut<SectionParams>| {
                         let v = change.value.round().clamp(40.0, 400.0);
                         p.num_points = v as usize;
                     },
@@ -89,9 +89,9 @@ pub(super) fn spawn_panel_settings(
         });
 }
 
-fn panel_count_label(params: &NacaParams) -> String {
+fn panel_count_label(params: &SectionParams) -> String {
     let total_panels =
-        build_naca_body_geometry(params).len().saturating_sub(1);
+        params.section().body_points().len().saturating_sub(1);
     format!(
         "Points per surface: {}  |  total panels: {}",
         params.num_points, total_panels
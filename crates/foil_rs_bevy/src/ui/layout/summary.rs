@@ -61,6 +61,11 @@ fn spawn_summary_table(table: &mut ChildSpawnerCommands<'_>) {
     };
 
     row("NACA code", TableField::NacaCode);
+    row("t/c max", TableField::MaxThickness);
+    row("Camber max", TableField::MaxCamber);
+    row("LE radius", TableField::LeRadius);
+    row("TE gap / angle", TableField::TrailingEdge);
+    row("Area", TableField::Area);
     row("α (deg)", TableField::AlphaDeg);
     row("Mach", TableField::Mach);
     row("Re (×10⁶)", TableField::Reynolds);
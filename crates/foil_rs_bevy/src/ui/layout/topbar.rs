@@ -12,7 +12,7 @@ use bevy::{
     text::TextFont,
 };
 
-use crate::state::NacaParams;
+use crate::state::SectionParams;
 
 use super::super::types::{
     CoeffModeButton, ExportPolarsButton, ExportStatusText,
@@ -26,7 +26,7 @@ const TOP_BAR_HEIGHT: f32 = 56.0;
 pub(super) fn spawn_top_bar(
     root: &mut ChildSpawnerCommands<'_>,
     asset_server: &AssetServer,
-    params: &NacaParams,
+    params: &SectionParams,
     mode: VisualMode,
     input_mode: UiInputMode,
     coeff_mode: UiCoeffMode,
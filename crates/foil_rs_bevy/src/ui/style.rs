@@ -1,4 +1,5 @@
 use crate::solvers::BoundaryLayerResult;
+use crate::solvers::panel::PanelMethod;
 
 use super::types::{FlowToggleKind, PanelSection};
 
@@ -34,6 +35,21 @@ pub(super) fn flow_toggle_label(
                 "Transition: Forced trip"
             }
         }
+        FlowToggleKind::LinearVorticity => {
+            let method = if active {
+                PanelMethod::LinearVorticity
+            } else {
+                PanelMethod::ConstantSource
+            };
+            match method {
+                PanelMethod::ConstantSource => {
+                    "Panels: Constant source"
+                }
+                PanelMethod::LinearVorticity => {
+                    "Panels: Linear vorticity"
+                }
+            }
+        }
     }
 }
 
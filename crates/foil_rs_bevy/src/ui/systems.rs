@@ -11,23 +11,27 @@ use bevy::{
     window::{PrimaryWindow, WindowResized},
 };
 
-use crate::solvers::panel::PanelLuSystem;
+use crate::airfoil::{
+    AirfoilShape, DatWriteOptions, GeometryProperties, save_dat,
+};
+use crate::solvers::panel::{PanelLuSystem, PanelMethod};
 use crate::solvers::{
-    BoundaryLayerInputs, compute_panel_solution,
-    estimate_boundary_layer,
+    BoundaryLayerInputs, estimate_boundary_layer,
+    try_compute_panel_solution,
 };
-use crate::state::{FlowSettings, NacaParams, cl_thin};
+use crate::state::{FlowSettings, SectionKey, SectionParams, cl_thin};
 
 use super::types::{
-    CoeffModeButton, ExportPolarsButton, ExportStatus,
-    ExportStatusText, FallbackWarningBadge, FlowAlphaControls,
-    FlowToggleKind, InputModeButton, InputSlider,
-    LeftPanelMainControls, LeftPanelPanelControls, NacaHeading,
-    NumericField, NumericInput, NumericInputFocus, NumericInputRow,
-    NumericInputText, PanelCountText, PanelSections,
-    PolarSweepSettings, PolarsControls, SectionContent, SectionToggle,
-    SolverDiagnostics, TableField, ThemeToggleButton, UiCoeffMode,
-    UiColorThemeMode, UiInputMode, ViewButton, VisualMode,
+    BlendPartnerText, CoeffModeButton, ExportDatButton,
+    ExportPolarsButton, ExportStatus, ExportStatusText,
+    FallbackWarningBadge, FlowAlphaControls, FlowToggleKind,
+    InputModeButton, InputSlider, LeftPanelMainControls,
+    LeftPanelPanelControls, NacaHeading, NumericField, NumericInput,
+    NumericInputFocus, NumericInputRow, NumericInputText,
+    PanelCountText, PanelSections, PolarSweepSettings, PolarsControls,
+    SectionContent, SectionToggle, SolverDiagnostics, TableField,
+    ThemeToggleButton, UiCoeffMode, UiColorThemeMode, UiInputMode,
+    ViewButton, VisualMode,
 };
 use super::{config, feathers_theme, style};
 use std::path::{Path, PathBuf};
@@ -147,7 +151,7 @@ pub fn handle_export_polars_button(
         &Interaction,
         (With<ExportPolarsButton>, Changed<Interaction>),
     >,
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     sweep: Res<PolarSweepSettings>,
     coeff_mode: Res<UiCoeffMode>,
@@ -170,7 +174,7 @@ pub fn handle_export_polars_button(
             }
         };
         let res = crate::solvers::polar::compute_polar_sweep_parallel_with_system_mode(
-            &params,
+            &params.section(),
             &flow,
             sweep.alpha_min_deg,
             sweep.alpha_max_deg,
@@ -179,8 +183,8 @@ pub fn handle_export_polars_button(
             threads,
             mode,
         );
+        let fallbacks = res.errors.len();
         let rows = res.rows;
-        let used_fallback = res.used_fallback;
 
         if let Err(err) = std::fs::create_dir_all("exports") {
             warn!("failed to create exports/: {err}");
@@ -212,8 +216,11 @@ pub fn handle_export_polars_button(
         match std::fs::write(&path, out) {
             Ok(()) => {
                 info!("exported polars to {}", path.display());
-                status.message = if used_fallback {
-                    format!("Saved (fallback used): {}", path.display())
+                status.message = if fallbacks > 0 {
+                    format!(
+                        "Saved (fallback at {fallbacks} α): {}",
+                        path.display()
+                    )
                 } else {
                     format!("Saved: {}", path.display())
                 };
@@ -226,6 +233,69 @@ pub fn handle_export_polars_button(
     }
 }
 
+pub fn handle_export_dat_button(
+    mut status: ResMut<ExportStatus>,
+    q: Query<
+        &Interaction,
+        (With<ExportDatButton>, Changed<Interaction>),
+    >,
+    params: Res<SectionParams>,
+) {
+    for interaction in &q {
+        if !matches!(*interaction, Interaction::Pressed) {
+            continue;
+        }
+
+        if let Err(err) = std::fs::create_dir_all("exports") {
+            warn!("failed to create exports/: {err}");
+            status.message = "Export failed".into();
+            return;
+        }
+
+        let section = params.section();
+        let name = section.name();
+        let path = next_available_dat_path(&name);
+        let options = DatWriteOptions {
+            name: Some(name),
+            ..default()
+        };
+        match save_dat(&path, &section.panel_points(), &options) {
+            Ok(()) => {
+                info!("exported geometry to {}", path.display());
+                status.message = format!("Saved: {}", path.display());
+            }
+            Err(err) => {
+                warn!("failed to export geometry: {err}");
+                status.message = "Export failed".into();
+            }
+        }
+    }
+}
+
+/// Show the partner code, and why the blend is rejected if it is.
+pub fn update_blend_partner_text(
+    params: Res<SectionParams>,
+    mut texts: Query<&mut Text, With<BlendPartnerText>>,
+) {
+    if !params.is_changed() {
+        return;
+    }
+    let label = blend_partner_label(&params);
+    for mut text in &mut texts {
+        text.0.clone_from(&label);
+    }
+}
+
+pub(super) fn blend_partner_label(params: &SectionParams) -> String {
+    let code = params.blend.partner.code();
+    match params.section().shape.try_panel_points() {
+        Ok(_) => format!("Blend partner: NACA {code}"),
+        Err(err) => {
+            format!("Blend partner: NACA {code} (blend ignored: {err})")
+        }
+    }
+}
+
 pub fn update_export_status_text(
     status: Res<ExportStatus>,
     mut texts: Query<&mut Text, With<ExportStatusText>>,
@@ -239,7 +309,7 @@ pub fn update_export_status_text(
 }
 
 fn next_available_export_path(
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
 ) -> PathBuf {
     let visc_tag = if flow.viscous { "visc" } else { "invisc" };
@@ -283,8 +353,46 @@ fn next_available_export_path(
     dir.join("polar_export.csv")
 }
 
+fn next_available_dat_path(section_name: &str) -> PathBuf {
+    let dir = Path::new("exports");
+    let stem = file_stem(section_name);
+    let mut path = dir.join(format!("{stem}.dat"));
+    if !path.exists() {
+        return path;
+    }
+
+    for i in 1..1000 {
+        path = dir.join(format!("{stem}_{i}.dat"));
+        if !path.exists() {
+            return path;
+        }
+    }
+
+    dir.join("geometry_export.dat")
+}
+
+/// Lower-case ASCII file stem for a section name: e.g.
+/// `NACA 2412 / NACA 4415 (50%) (flap +10.0°)` becomes
+/// `naca_2412_naca_4415_50_flap_+10.0`.
+fn file_stem(name: &str) -> String {
+    let mut stem = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') {
+            stem.push(c.to_ascii_lowercase());
+        } else if !stem.is_empty() && !stem.ends_with('_') {
+            stem.push('_');
+        }
+    }
+    let stem = stem.trim_matches(['_', '.']);
+    if stem.is_empty() {
+        "geometry_export".into()
+    } else {
+        stem.into()
+    }
+}
+
 pub fn update_naca_heading(
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     mut headings: Query<&mut Text, With<NacaHeading>>,
 ) {
     if !params.is_changed() {
@@ -362,16 +470,14 @@ pub fn update_left_panel_visibility(
 }
 
 pub fn update_panel_count_text(
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     mut texts: Query<&mut Text, With<PanelCountText>>,
 ) {
     if !params.is_changed() {
         return;
     }
     let total_panels =
-        crate::airfoil::build_naca_body_geometry(&params)
-            .len()
-            .saturating_sub(1);
+        params.section().body_points().len().saturating_sub(1);
     let label = format!(
         "Points per surface: {}  |  total panels: {}",
         params.num_points, total_panels
@@ -387,7 +493,7 @@ pub fn handle_numeric_input_focus(
         (Entity, &Interaction, &NumericInput),
         Changed<Interaction>,
     >,
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     sweep: Res<PolarSweepSettings>,
     input_mode: Res<UiInputMode>,
@@ -413,7 +519,7 @@ pub fn handle_numeric_input_edit(
     mut focus: ResMut<NumericInputFocus>,
     keys: Res<ButtonInput<KeyCode>>,
     inputs: Query<&NumericInput>,
-    mut params: ResMut<NacaParams>,
+    mut params: ResMut<SectionParams>,
     mut flow: ResMut<FlowSettings>,
     mut sweep: ResMut<PolarSweepSettings>,
     input_mode: Res<UiInputMode>,
@@ -493,7 +599,7 @@ pub fn sync_numeric_inputs(
         Option<&ThemeBorderColor>,
     )>,
     mut texts: Query<(&NumericInputText, &mut Text)>,
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     sweep: Res<PolarSweepSettings>,
     input_mode: Res<UiInputMode>,
@@ -550,7 +656,7 @@ pub fn sync_numeric_inputs(
 
 fn format_numeric_value(
     field: NumericField,
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
     sweep: &PolarSweepSettings,
     meta: &NumericInput,
@@ -559,6 +665,17 @@ fn format_numeric_value(
         NumericField::NacaMDigit => params.m_digit,
         NumericField::NacaPDigit => params.p_digit,
         NumericField::NacaTDigits => params.t_digits,
+        NumericField::FlapDeflectionDeg => params.flap.deflection_deg,
+        NumericField::BlendPartnerMDigit => {
+            params.blend.partner.m_digit
+        }
+        NumericField::BlendPartnerPDigit => {
+            params.blend.partner.p_digit
+        }
+        NumericField::BlendPartnerTDigits => {
+            params.blend.partner.t_digits
+        }
+        NumericField::BlendPercent => params.blend.percent,
         NumericField::AlphaDeg => flow.alpha_deg,
         NumericField::ReynoldsMillions => flow.reynolds / 1_000_000.0,
         NumericField::Mach => flow.mach,
@@ -584,7 +701,7 @@ fn format_numeric_value(
 fn set_numeric_value(
     field: NumericField,
     raw: f32,
-    params: &mut NacaParams,
+    params: &mut SectionParams,
     flow: &mut FlowSettings,
     sweep: &mut PolarSweepSettings,
     meta: &NumericInput,
@@ -598,6 +715,19 @@ fn set_numeric_value(
         NumericField::NacaMDigit => params.m_digit = v,
         NumericField::NacaPDigit => params.p_digit = v,
         NumericField::NacaTDigits => params.t_digits = v,
+        NumericField::FlapDeflectionDeg => {
+            params.flap.deflection_deg = v
+        }
+        NumericField::BlendPartnerMDigit => {
+            params.blend.partner.m_digit = v
+        }
+        NumericField::BlendPartnerPDigit => {
+            params.blend.partner.p_digit = v
+        }
+        NumericField::BlendPartnerTDigits => {
+            params.blend.partner.t_digits = v
+        }
+        NumericField::BlendPercent => params.blend.percent = v,
         NumericField::AlphaDeg => flow.alpha_deg = v,
         NumericField::ReynoldsMillions => {
             flow.reynolds = v * 1_000_000.0
@@ -788,7 +918,7 @@ pub fn update_numeric_input_visibility(
 }
 
 pub fn update_table_text(
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     coeff_mode: Res<UiCoeffMode>,
     mut query: Query<(&mut Text, &TableField)>,
@@ -802,41 +932,41 @@ pub fn update_table_text(
     }
 
     let cl = cl_thin(flow.alpha_deg);
-    let key = PanelKey::from(&*params);
+    let section = params.section();
+    let key = SectionKey::new(&params, flow.panel_method);
     if cache.key != Some(key) {
         cache.key = Some(key);
-        cache.system = PanelLuSystem::new(&params);
+        cache.system =
+            PanelLuSystem::with_method(&section, flow.panel_method);
     }
 
     let panel_sol = match *coeff_mode {
         UiCoeffMode::Approx => {
             crate::solvers::panel::compute_approx_solution(
-                &params,
+                &section,
                 flow.alpha_deg,
             )
         }
         UiCoeffMode::Panel => {
-            let sol = cache
-                .system
-                .as_ref()
-                .map(|sys| sys.panel_solution(&params, flow.alpha_deg))
-                .unwrap_or_else(|| {
-                    compute_panel_solution(&params, flow.alpha_deg)
-                });
-            if sol.x.is_empty() {
+            let sol = match cache.system.as_ref() {
+                Some(sys) => sys.try_panel_solution(flow.alpha_deg),
+                None => {
+                    try_compute_panel_solution(&section, flow.alpha_deg)
+                }
+            };
+            sol.unwrap_or_else(|_| {
                 crate::solvers::panel::compute_approx_solution(
-                    &params,
+                    &section,
                     flow.alpha_deg,
                 )
-            } else {
-                sol
-            }
+            })
         }
     };
     let est_cl = panel_sol.cl().unwrap_or(f32::NAN);
     let est_cm = panel_sol.cm_c4().unwrap_or(f32::NAN);
     let beta = (1.0 - flow.mach * flow.mach).clamp(0.05, 1.0).sqrt();
     let est_cl_corr = est_cl / beta;
+    let est_cm_corr = est_cm / beta;
     let bl_inputs = BoundaryLayerInputs::new(
         flow.reynolds,
         flow.mach,
@@ -857,6 +987,10 @@ pub fn update_table_text(
     } else {
         "--".into()
     };
+    let geometry = GeometryProperties::of(&section);
+    let geometry_text = |f: fn(&GeometryProperties) -> String| {
+        geometry.as_ref().map(f).unwrap_or_else(|| "--".into())
+    };
 
     for (mut text, field) in &mut query {
         text.0 = match field {
@@ -882,35 +1016,42 @@ pub fn update_table_text(
             }
             TableField::ClThin => format!("{:.3}", cl),
             TableField::RefCl => format!("{:.4}", est_cl_corr),
-            TableField::RefCm => format!("{:.4}", est_cm),
+            TableField::RefCm => format!("{:.4}", est_cm_corr),
             TableField::RefCdp => est_cdp_text.clone(),
             TableField::FlowState => flow_state_text.clone(),
+            TableField::MaxThickness => geometry_text(|g| {
+                format!(
+                    "{:.2}% @ {:.3}",
+                    100.0 * g.max_thickness,
+                    g.max_thickness_x
+                )
+            }),
+            TableField::MaxCamber => geometry_text(|g| {
+                format!(
+                    "{:.2}% @ {:.3}",
+                    100.0 * g.max_camber,
+                    g.max_camber_x
+                )
+            }),
+            TableField::LeRadius => {
+                geometry_text(|g| format!("{:.4}", g.le_radius))
+            }
+            TableField::TrailingEdge => geometry_text(|g| {
+                format!(
+                    "{:.4} / {:.1}°",
+                    g.te_thickness, g.te_angle_deg
+                )
+            }),
+            TableField::Area => {
+                geometry_text(|g| format!("{:.4}", g.area))
+            }
         };
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub struct PanelKey {
-    m: u8,
-    p: u8,
-    t: u16,
-    num_points: usize,
-}
-
-impl From<&NacaParams> for PanelKey {
-    fn from(params: &NacaParams) -> Self {
-        Self {
-            m: params.m_digit.round().clamp(0.0, 9.0) as u8,
-            p: params.p_digit.round().clamp(0.0, 9.0) as u8,
-            t: params.t_digits.round().clamp(0.0, 99.0) as u16,
-            num_points: params.num_points,
-        }
-    }
-}
-
 #[derive(Default)]
 pub struct UiPanelSystemCache {
-    key: Option<PanelKey>,
+    key: Option<SectionKey>,
     system: Option<PanelLuSystem>,
 }
 
@@ -1035,12 +1176,25 @@ pub fn handle_flow_toggle_buttons(
                 FlowToggleKind::Transition => {
                     flow.free_transition = !flow.free_transition;
                 }
+                FlowToggleKind::LinearVorticity => {
+                    flow.panel_method = match flow.panel_method {
+                        PanelMethod::ConstantSource => {
+                            PanelMethod::LinearVorticity
+                        }
+                        PanelMethod::LinearVorticity => {
+                            PanelMethod::ConstantSource
+                        }
+                    };
+                }
             }
         }
 
         let active = match kind {
             FlowToggleKind::Viscosity => flow.viscous,
             FlowToggleKind::Transition => flow.free_transition,
+            FlowToggleKind::LinearVorticity => {
+                flow.panel_method == PanelMethod::LinearVorticity
+            }
         };
         let desired_bg = if active {
             tokens::BUTTON_PRIMARY_BG
@@ -1069,3 +1223,37 @@ pub fn handle_flow_toggle_buttons(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{blend_partner_label, file_stem};
+    use crate::state::SectionParams;
+
+    #[test]
+    fn export_names_follow_the_section() {
+        assert_eq!(file_stem("NACA 2412"), "naca_2412");
+        assert_eq!(
+            file_stem("NACA 2412 / NACA 4415 (50%) (flap +10.0°)"),
+            "naca_2412_naca_4415_50_flap_+10.0"
+        );
+        assert_eq!(file_stem("../°"), "geometry_export");
+    }
+
+    #[test]
+    fn blend_partner_label_reports_rejected_blends() {
+        let mut params = SectionParams::default();
+        params.naca.t_digits = 12.0;
+        params.blend.partner.t_digits = 6.0;
+        params.blend.percent = 50.0;
+        assert_eq!(
+            blend_partner_label(&params),
+            "Blend partner: NACA 4406"
+        );
+        params.blend.percent = 250.0;
+        assert!(
+            blend_partner_label(&params).contains("blend ignored"),
+            "{}",
+            blend_partner_label(&params)
+        );
+    }
+}
@@ -37,6 +37,11 @@ pub enum TableField {
     FlowState,
     ViscosityMode,
     TransitionMode,
+    MaxThickness,
+    MaxCamber,
+    LeRadius,
+    TrailingEdge,
+    Area,
 }
 
 #[derive(Resource, Clone, Copy, PartialEq, Eq)]
@@ -170,6 +175,8 @@ pub struct SectionContent {
 pub enum FlowToggleKind {
     Viscosity,
     Transition,
+    /// Linear-vorticity (XFoil) vs constant-source panel method.
+    LinearVorticity,
 }
 
 #[derive(Component)]
@@ -185,9 +192,15 @@ pub struct InputModeButton {
 #[derive(Component)]
 pub struct ExportPolarsButton;
 
+#[derive(Component)]
+pub struct ExportDatButton;
+
 #[derive(Component)]
 pub struct ExportStatusText;
 
+#[derive(Component)]
+pub struct BlendPartnerText;
+
 #[derive(Component)]
 pub struct ThemeToggleButton;
 
@@ -233,6 +246,11 @@ pub enum NumericField {
     NacaMDigit,
     NacaPDigit,
     NacaTDigits,
+    FlapDeflectionDeg,
+    BlendPartnerMDigit,
+    BlendPartnerPDigit,
+    BlendPartnerTDigits,
+    BlendPercent,
     AlphaDeg,
     ReynoldsMillions,
     Mach,
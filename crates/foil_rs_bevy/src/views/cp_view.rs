@@ -2,8 +2,8 @@ use bevy::{color::palettes::css, prelude::*};
 
 use crate::{
     plotter::{CpPlotLabels, refresh_cp_labels},
-    solvers,
-    state::{FlowSettings, NacaParams},
+    solvers::{self, panel::cp_plot_y},
+    state::{FlowSettings, SectionParams},
     ui::UiCoeffMode,
     views::CHORD_PX,
 };
@@ -34,7 +34,7 @@ fn cp_corrected(cp: f32, flow: &FlowSettings) -> f32 {
 }
 
 pub(super) fn compute_cp_graph_primitives(
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
     base_y: f32,
     scale_y: f32,
@@ -42,29 +42,27 @@ pub(super) fn compute_cp_graph_primitives(
     coeff_mode: UiCoeffMode,
 ) -> (Option<CpGraphPrimitives>, bool) {
     let mut used_fallback = false;
+    let section = params.section();
     let sol = match coeff_mode {
         UiCoeffMode::Approx => solvers::panel::compute_approx_solution(
-            params,
+            &section,
             flow.alpha_deg,
         ),
         UiCoeffMode::Panel => {
-            let sol = panel_system
-                .map(|sys| sys.panel_solution(params, flow.alpha_deg))
-                .unwrap_or_else(|| {
-                    solvers::compute_panel_solution(
-                        params,
-                        flow.alpha_deg,
-                    )
-                });
-            if sol.x.is_empty() {
+            let sol = match panel_system {
+                Some(sys) => sys.try_panel_solution(flow.alpha_deg),
+                None => solvers::try_compute_panel_solution(
+                    &section,
+                    flow.alpha_deg,
+                ),
+            };
+            sol.unwrap_or_else(|_| {
                 used_fallback = true;
                 solvers::panel::compute_approx_solution(
-                    params,
+                    &section,
                     flow.alpha_deg,
                 )
-            } else {
-                sol
-            }
+            })
         }
     };
 
@@ -77,8 +75,14 @@ pub(super) fn compute_cp_graph_primitives(
         let cp_l = cp_corrected(sol.cp_lower[i], flow);
 
         let world_x = (x - 0.5) * CHORD_PX;
-        upper_pts.push(Vec2::new(world_x, base_y - cp_u * scale_y));
-        lower_pts.push(Vec2::new(world_x, base_y - cp_l * scale_y));
+        upper_pts.push(Vec2::new(
+            world_x,
+            base_y + cp_plot_y(cp_u) * scale_y,
+        ));
+        lower_pts.push(Vec2::new(
+            world_x,
+            base_y + cp_plot_y(cp_l) * scale_y,
+        ));
     }
 
     (
@@ -123,7 +127,7 @@ pub(super) fn draw_cp_graph_primitives(
     }
     let cp_ticks = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0];
     for &cp in &cp_ticks {
-        let wy = base_y - cp * scale_y;
+        let wy = base_y + cp_plot_y(cp) * scale_y;
         let tick_len = 10.0;
         gizmos.line_2d(
             Vec2::new(axis_left.x - tick_len, wy),
@@ -162,6 +166,7 @@ mod tests {
             mach,
             viscous,
             free_transition: true,
+            ..Default::default()
         })
     }
 
@@ -23,7 +23,7 @@ pub(super) fn compute_field_primitives(
     let mut prims = FieldPrimitives::default();
     let alpha_rad = flow.alpha_deg.to_radians();
     let panel_flow =
-        panel_system.and_then(|sys| sys.solve_flow(flow.alpha_deg));
+        panel_system.map(|sys| sys.solve_flow(flow.alpha_deg));
     compute_arrow_lines(
         alpha_rad,
         flow,
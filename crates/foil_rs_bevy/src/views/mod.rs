@@ -4,9 +4,9 @@ use bevy::{
     prelude::*,
 };
 
-use crate::airfoil::build_naca_body_geometry;
+use crate::airfoil::AirfoilShape;
 use crate::plotter::{CpPlotLabels, PolarPlotLabels};
-use crate::state::{FlowSettings, NacaParams};
+use crate::state::{FlowSettings, SectionKey, SectionParams};
 use crate::ui::{
     PolarSweepSettings, SolverDiagnostics, UiCoeffMode, VisualMode,
 };
@@ -33,28 +33,9 @@ use polar_view::{
 
 pub(crate) const CHORD_PX: f32 = 450.0;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-struct NacaKey {
-    m: u8,
-    p: u8,
-    t: u8,
-    num_points: usize,
-}
-
-impl From<&NacaParams> for NacaKey {
-    fn from(params: &NacaParams) -> Self {
-        Self {
-            m: params.m_digit.round().clamp(0.0, 9.0) as u8,
-            p: params.p_digit.round().clamp(0.0, 9.0) as u8,
-            t: params.t_digits.round().clamp(0.0, 99.0) as u8,
-            num_points: params.num_points,
-        }
-    }
-}
-
 #[derive(Default)]
 pub struct VizCache {
-    naca_key: Option<NacaKey>,
+    naca_key: Option<SectionKey>,
     body: Vec<Vec2>,
 
     body_world_alpha_bits: Option<u32>,
@@ -62,20 +43,30 @@ pub struct VizCache {
 
     panel_system: Option<crate::solvers::panel::PanelLuSystem>,
 
-    field_key: Option<(NacaKey, u32, u32, u32, bool)>,
+    field_key: Option<(SectionKey, u32, u32, u32, bool)>,
     field_prims: FieldPrimitives,
 
-    panel_key: Option<(NacaKey, u32)>,
+    panel_key: Option<(SectionKey, u32)>,
     panel_prims: PanelPrimitives,
 
-    cp_key: Option<(NacaKey, u32, u32, u32, u32, u32, bool, u8)>,
+    cp_key: Option<(SectionKey, u32, u32, u32, u32, u32, bool, u8)>,
     cp_prims: Option<CpGraphPrimitives>,
     cp_used_fallback: bool,
     cp_labels_key: Option<(u32, u32)>,
     cp_labels_dirty: bool,
 
-    polar_key:
-        Option<(NacaKey, u32, u32, bool, bool, u32, u32, u32, u8, u8)>,
+    polar_key: Option<(
+        SectionKey,
+        u32,
+        u32,
+        bool,
+        bool,
+        u32,
+        u32,
+        u32,
+        u8,
+        u8,
+    )>,
     polar_prims: Option<PolarGraphPrimitives>,
     polar_labels_dirty: bool,
 }
@@ -86,7 +77,7 @@ pub fn draw_airfoil_and_visualization(
     asset_server: Res<AssetServer>,
     mut cp_labels: ResMut<CpPlotLabels>,
     mut polar_labels: ResMut<PolarPlotLabels>,
-    params: Res<NacaParams>,
+    params: Res<SectionParams>,
     flow: Res<FlowSettings>,
     sweep: Res<PolarSweepSettings>,
     coeff_mode: Res<UiCoeffMode>,
@@ -103,12 +94,30 @@ pub fn draw_airfoil_and_visualization(
     };
     let alpha_rad = draw_alpha_deg.to_radians();
 
-    let naca_key = NacaKey::from(&*params);
+    let naca_key = SectionKey::new(&params, flow.panel_method);
     if cache.naca_key != Some(naca_key) {
         cache.naca_key = Some(naca_key);
-        cache.body = build_naca_body_geometry(&params);
+        let section = params.section();
+        cache.body = section.body_points();
         cache.panel_system =
-            crate::solvers::panel::PanelLuSystem::new(&params);
+            match crate::solvers::panel::PanelLuSystem::try_with_method(
+                &section,
+                flow.panel_method,
+            ) {
+                Ok(system) => {
+                    for warning in system.warnings() {
+                        warn!("{}: {warning}", params.code());
+                    }
+                    Some(system)
+                }
+                Err(err) => {
+                    warn!(
+                        "{}: panel solver unavailable: {err}",
+                        params.code()
+                    );
+                    None
+                }
+            };
         cache.body_world_alpha_bits = None;
         cache.field_key = None;
         cache.panel_key = None;
@@ -2,7 +2,7 @@ use bevy::{color::palettes::css, prelude::*};
 
 use crate::{
     plotter::{PolarPlotLabels, refresh_polar_labels},
-    state::{FlowSettings, NacaParams},
+    state::{FlowSettings, SectionParams},
     ui::UiCoeffMode,
     views::CHORD_PX,
 };
@@ -24,7 +24,7 @@ pub(super) struct PolarGraphPrimitives {
 }
 
 pub(super) fn compute_polar_primitives(
-    params: &NacaParams,
+    params: &SectionParams,
     flow: &FlowSettings,
     alpha_min_deg: f32,
     alpha_max_deg: f32,
@@ -38,7 +38,7 @@ pub(super) fn compute_polar_primitives(
         UiCoeffMode::Approx => crate::solvers::polar::PolarMode::Approx,
     };
     let res = crate::solvers::polar::compute_polar_sweep_parallel_with_system_mode(
-        params,
+        &params.section(),
         flow,
         alpha_min_deg,
         alpha_max_deg,
@@ -47,8 +47,8 @@ pub(super) fn compute_polar_primitives(
         threads,
         mode,
     );
+    let used_fallback = res.used_fallback();
     let rows = res.rows;
-    let used_fallback = res.used_fallback;
 
     let mut cl_pts = Vec::with_capacity(rows.len());
     let mut cd_pts = Vec::with_capacity(rows.len());